@@ -3,7 +3,8 @@
 //! Almost everything in D&D ultimately revolves around your characters'
 //! abilities.
 //!
-//! This crate contains ability types, ability scores, modifiers, and more.
+//! This crate contains ability types, ability scores, modifiers, skills,
+//! saving throws, and more.
 //!
 #![warn(
     clippy::pedantic,
@@ -18,11 +19,13 @@
 )]
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt,
     hash::Hash,
+    ops::RangeBounds,
 };
 
-use dice::Die;
+use dice::{Die, Roll};
 use itertools::Itertools;
 use rand::{
     distributions::Standard,
@@ -30,9 +33,18 @@ use rand::{
     seq::{IteratorRandom, SliceRandom},
     Rng,
 };
-use rand_utils::SliceExpRandom;
+use rand_utils::{GeneratorVersion, SliceExpRandom};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator};
+use thiserror::Error;
+
+pub use crate::{
+    point_buy::{point_buy_cost, validate_point_buy, PointBuyError, POINT_BUY_BUDGET},
+    skills::{proficiency_bonus, Proficiency, Skill, DEFAULT_PROFICIENCY_BONUS},
+};
+
+mod point_buy;
+mod skills;
 
 /// Available Ability types
 /// Ordered in the same order as a character sheet.
@@ -77,14 +89,76 @@ pub enum Ability {
     Charisma,
 }
 
+/// Ways of generating the base ability scores for a character
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum GenerationMethod {
+    /// Roll 4d6 and drop the lowest die for each score
+    #[default]
+    Roll,
+    /// Spend 27 points on scores between 8 and 15
+    PointBuy,
+    /// Use the fixed scores 15, 14, 13, 12, 10, and 8
+    StandardArray,
+    /// Roll 3d6 for each score and assign them to abilities in order, rather
+    /// than by combat role
+    RollInOrder,
+    /// Roll 4d6 for each score, rerolling 1s once, and drop the lowest die
+    RollRerollOnes,
+    /// Roll seven scores with 4d6, dropping the lowest die, and discard the
+    /// lowest score
+    RollSevenDropWorst,
+}
+
+/// Rules for which abilities a race's ability score increases apply to
+#[derive(Clone, Copy, Debug, Default, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum RacialIncreaseRule {
+    /// Increases go to the abilities listed for the race
+    Fixed,
+    /// Increases can go to any abilities, as with the customizing your
+    /// origin rules from Tasha's Cauldron of Everything
+    #[default]
+    Floating,
+}
+
+/// Scores used by the standard array, in descending order
+const STANDARD_ARRAY: [u8; 6] = [15, 14, 13, 12, 10, 8];
+
 /// Different combat roles from "Live to Tell the Tale" p.22
-#[derive(Clone, Copy, Debug, Display, EnumIter)]
-enum CombatRole {
+///
+/// Used to decide which abilities get the highest scores when they are
+/// generated.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum CombatRole {
+    /// Stands in the thick of the fight and soaks up damage
     FrontLine,
+    /// Deals heavy damage up close, then gets out of the way
     ShockAttacker,
+    /// Stays mobile and picks off enemies on the edges of a fight
     Skirmisher,
+    /// Attacks from range with weapons
     Marksman,
+    /// Heals and strengthens allies
     Supporter,
+    /// Attacks with spells
     Spellslinger,
 }
 
@@ -135,7 +209,14 @@ pub struct AbilityScore {
 
 impl AbilityScore {
     /// Create a new ability score
-    fn new(ability: Ability, score: u8) -> Self {
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScore};
+    ///
+    /// let strength = AbilityScore::new(Ability::Strength, 15);
+    /// ```
+    #[must_use]
+    pub fn new(ability: Ability, score: u8) -> Self {
         metrics::counter!(
             "abilities_score",
             &[
@@ -157,6 +238,10 @@ struct AbilityScoreTotal {
     base: u8,
     /// Increase provided by race
     racial_increase: u8,
+    /// Increase from Ability Score Improvements gained by leveling up. Left
+    /// out before V2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    improvement: Option<u8>,
     /// Total score for this ability
     score: u8,
     /// Modifier derived from the total score
@@ -169,12 +254,13 @@ impl AbilityScoreTotal {
     /// # Panics
     ///
     /// Will only panic if we somehow generate an invalid value for D&D
-    fn new(base: u8, racial_increase: u8) -> Self {
-        let score = base + racial_increase;
+    fn new(base: u8, racial_increase: u8, improvement: u8) -> Self {
+        let score = base + racial_increase + improvement;
         let i_score: i8 = score.try_into().unwrap();
         Self {
             base,
             racial_increase,
+            improvement: Some(improvement),
             score,
             // Lower value to closest even number, reduce by 10, and divide by two
             modifier: (i_score - i_score % 2 - 10) / 2,
@@ -182,14 +268,49 @@ impl AbilityScoreTotal {
     }
 }
 
+/// Lowest score an ability can have
+const MIN_SCORE: u8 = 1;
+
+/// Highest score an ability can have
+const MAX_SCORE: u8 = 30;
+
+/// Highest score racial increases and Ability Score Improvements can raise
+/// an ability to
+const MAX_INCREASED_SCORE: u8 = 20;
+
+/// Highest level a character can reach
+pub const MAX_LEVEL: u8 = 20;
+
+/// Levels at which a character gains an Ability Score Improvement
+pub const IMPROVEMENT_LEVELS: [u8; 5] = [4, 8, 12, 16, 19];
+
+/// Points to spend for each Ability Score Improvement
+const IMPROVEMENT_POINTS: u8 = 2;
+
 /// A collection of ability scores
-#[derive(Clone, Debug, Serialize)]
-#[serde(into = "AbilityScoreStats")]
+///
+/// Can be deserialized from a map of each ability to its base score, such as
+/// `{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(into = "AbilityScoreStats", try_from = "BTreeMap<Ability, u8>")]
 pub struct AbilityScores {
     /// Base scores generated for this character
     base_scores: HashSet<AbilityScore>,
     /// Ability score increases chosen for the race
     racial_increases: HashSet<AbilityScore>,
+    /// Total increase for each ability from Ability Score Improvements
+    improvements: BTreeMap<Ability, u8>,
+    /// Combat role the scores were assigned for, if any
+    combat_role: Option<CombatRole>,
+    /// Bonus added to skills and saving throws the character is proficient in
+    proficiency_bonus: i8,
+    /// Skills the character is proficient in
+    skill_proficiencies: BTreeMap<Skill, Proficiency>,
+    /// Saving throws the character is proficient in
+    saving_throw_proficiencies: BTreeMap<Ability, Proficiency>,
+    /// Version of the generator describing these scores, which decides what
+    /// is serialized
+    version: GeneratorVersion,
 }
 
 impl AbilityScores {
@@ -198,16 +319,70 @@ impl AbilityScores {
     /// Most likely you will generate this with `rng.gen()`, but can be created
     /// manually as well if necessary.
     ///
-    /// # Panics
+    /// ```
+    /// use abilities::{Ability, AbilityScore, AbilityScores};
+    /// use strum::IntoEnumIterator;
     ///
-    /// Will panic if not all 6 scores are present
-    #[must_use]
-    pub fn new(base_scores: HashSet<AbilityScore>) -> Self {
-        assert_eq!(base_scores.len(), 6);
-        Self {
+    /// let scores = AbilityScores::new(
+    ///     Ability::iter().map(|a| AbilityScore::new(a, 10)).collect(),
+    /// )?;
+    /// assert_eq!(scores.score(Ability::Wisdom), 10);
+    /// # Ok::<(), abilities::AbilityScoresError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if there isn't exactly one score for every ability, or if a
+    /// score is outside of the range 1-30.
+    pub fn new(base_scores: HashSet<AbilityScore>) -> Result<Self, AbilityScoresError> {
+        for ability in Ability::iter() {
+            match base_scores.iter().filter(|s| s.ability == ability).count() {
+                0 => return Err(AbilityScoresError::MissingAbility(ability)),
+                1 => {}
+                _ => return Err(AbilityScoresError::DuplicateAbility(ability)),
+            }
+        }
+        if let Some(&AbilityScore { ability, score }) = base_scores
+            .iter()
+            .find(|s| !(MIN_SCORE..=MAX_SCORE).contains(&s.score))
+        {
+            return Err(AbilityScoresError::ScoreOutOfRange { ability, score });
+        }
+
+        Ok(Self {
             base_scores,
             racial_increases: HashSet::new(),
-        }
+            improvements: BTreeMap::new(),
+            combat_role: None,
+            proficiency_bonus: DEFAULT_PROFICIENCY_BONUS,
+            skill_proficiencies: BTreeMap::new(),
+            saving_throw_proficiencies: BTreeMap::new(),
+            version: GeneratorVersion::LATEST,
+        })
+    }
+
+    /// Check that the base scores are a legal point buy, returning the total
+    /// number of points spent.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use abilities::{Ability, AbilityScores};
+    /// use strum::IntoEnumIterator;
+    ///
+    /// let scores = AbilityScores::try_from(
+    ///     Ability::iter().zip([15, 15, 15, 8, 8, 8]).collect::<BTreeMap<_, _>>(),
+    /// )?;
+    /// assert_eq!(scores.validate_point_buy(), Ok(27));
+    /// # Ok::<(), abilities::AbilityScoresError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if any base score can't be bought, or if more points are
+    /// spent than the budget allows.
+    pub fn validate_point_buy(&self) -> Result<u8, PointBuyError> {
+        point_buy::validate_point_buy(self.base_scores.iter().map(|s| s.score))
     }
 
     /// Internal method to access a given ability score
@@ -230,7 +405,7 @@ impl AbilityScores {
             .map(|s| s.score)
             .unwrap_or_default();
 
-        AbilityScoreTotal::new(base, racial_increase)
+        AbilityScoreTotal::new(base, racial_increase, self.improvement(ability))
     }
 
     /// Get a specific ability score.
@@ -263,25 +438,195 @@ impl AbilityScores {
         self.ability(ability).modifier
     }
 
-    /// Choose a single racial increase.
+    /// Combat role the scores were assigned for. Only available for scores
+    /// generated by a method that assigns scores by combat role.
     ///
-    /// Will weight choices where possible towards applying increases to
-    /// ability scores that would cause in increase in the modifier.
+    /// ```
+    /// use abilities::{AbilityScores, CombatRole, GenerationMethod};
     ///
-    /// # Panics
+    /// let mut rng = rand::thread_rng();
+    /// let scores =
+    ///     AbilityScores::gen_with_role(&mut rng, GenerationMethod::Roll, CombatRole::Marksman);
+    /// assert_eq!(scores.combat_role(), Some(CombatRole::Marksman));
+    /// ```
+    #[must_use]
+    pub fn combat_role(&self) -> Option<CombatRole> {
+        self.combat_role
+    }
+
+    /// These scores as a specific version of the generator describes them.
+    /// The combat role scores were assigned for, Ability Score Improvements,
+    /// the proficiency bonus, and skill and saving throw bonuses were only
+    /// reported from V2 onward, so V1 leaves them out.
     ///
-    /// Panics if an increase cannot be chosen, which shouldn't be possible.
-    #[tracing::instrument(skip(rng))]
-    fn gen_racial_increase<R: Rng + ?Sized>(&mut self, rng: &mut R, increase: u8) -> &mut Self {
-        // Get all abilities that haven't already been chosen for racial increases.
-        let abilities = Ability::iter().collect::<HashSet<_>>();
-        let current_racial_increases = self.racial_increases.iter().map(|i| i.ability).collect();
-        let remaining_abilities = abilities.difference(&current_racial_increases);
+    /// Call this once the scores are finished, since the combat role also
+    /// decides where Ability Score Improvements go.
+    ///
+    /// ```
+    /// use abilities::{AbilityScores, CombatRole, GenerationMethod};
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let scores =
+    ///     AbilityScores::gen_with_role(&mut rng, GenerationMethod::Roll, CombatRole::Marksman);
+    /// assert_eq!(scores.for_version(GeneratorVersion::V1).combat_role(), None);
+    /// ```
+    #[must_use]
+    pub fn for_version(mut self, version: GeneratorVersion) -> Self {
+        if version == GeneratorVersion::V1 {
+            self.combat_role = None;
+        }
+        self.version = version;
+        self
+    }
 
-        // Filter out options that aren't valid choices
-        let all_ability_choices = remaining_abilities
+    /// Bonus added to skills and saving throws the character is proficient
+    /// in. Starts at 2, the bonus for a 1st level character.
+    #[must_use]
+    pub fn proficiency_bonus(&self) -> i8 {
+        self.proficiency_bonus
+    }
+
+    /// Change the proficiency bonus, usually because the character gained a
+    /// level.
+    pub fn set_proficiency_bonus(&mut self, proficiency_bonus: i8) -> &mut Self {
+        self.proficiency_bonus = proficiency_bonus;
+        self
+    }
+
+    /// Proficiency the character has in a skill
+    #[must_use]
+    pub fn skill_proficiency(&self, skill: Skill) -> Proficiency {
+        self.skill_proficiencies
+            .get(&skill)
             .copied()
-            .filter(|&a| self.ability(a).score + increase <= 20)
+            .unwrap_or_default()
+    }
+
+    /// Set the proficiency the character has in a skill
+    ///
+    /// ```
+    /// use abilities::{AbilityScores, Proficiency, Skill};
+    /// use rand::Rng;
+    ///
+    /// let mut scores: AbilityScores = rand::thread_rng().gen();
+    /// scores.set_skill_proficiency(Skill::Stealth, Proficiency::Expertise);
+    /// assert_eq!(scores.skill_proficiency(Skill::Stealth), Proficiency::Expertise);
+    /// ```
+    pub fn set_skill_proficiency(&mut self, skill: Skill, proficiency: Proficiency) -> &mut Self {
+        self.skill_proficiencies.insert(skill, proficiency);
+        self
+    }
+
+    /// Total bonus for a skill check, from the governing ability's modifier
+    /// and any proficiency.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores, Proficiency, Skill};
+    /// use rand::Rng;
+    ///
+    /// let mut scores: AbilityScores = rand::thread_rng().gen();
+    /// let modifier = scores.modifier(Ability::Dexterity);
+    /// assert_eq!(scores.skill_bonus(Skill::Stealth), modifier);
+    ///
+    /// scores.set_skill_proficiency(Skill::Stealth, Proficiency::Proficient);
+    /// assert_eq!(scores.skill_bonus(Skill::Stealth), modifier + 2);
+    /// ```
+    #[must_use]
+    pub fn skill_bonus(&self, skill: Skill) -> i8 {
+        self.modifier(skill.ability()) + self.skill_proficiency(skill).bonus(self.proficiency_bonus)
+    }
+
+    /// Proficiency the character has in an ability's saving throw
+    #[must_use]
+    pub fn saving_throw_proficiency(&self, ability: Ability) -> Proficiency {
+        self.saving_throw_proficiencies
+            .get(&ability)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Set the proficiency the character has in an ability's saving throw
+    pub fn set_saving_throw_proficiency(
+        &mut self,
+        ability: Ability,
+        proficiency: Proficiency,
+    ) -> &mut Self {
+        self.saving_throw_proficiencies.insert(ability, proficiency);
+        self
+    }
+
+    /// Make the character proficient in the given skills and saving throws,
+    /// with expertise in the skills in `expertise`.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores, Proficiency, Skill};
+    /// use rand::Rng;
+    ///
+    /// let mut scores: AbilityScores = rand::thread_rng().gen();
+    /// scores.set_proficiencies(&[Skill::Arcana], &[Skill::Stealth], &[Ability::Wisdom]);
+    /// assert_eq!(scores.skill_proficiency(Skill::Arcana), Proficiency::Proficient);
+    /// assert_eq!(scores.skill_proficiency(Skill::Stealth), Proficiency::Expertise);
+    /// assert_eq!(scores.saving_throw_proficiency(Ability::Wisdom), Proficiency::Proficient);
+    /// ```
+    pub fn set_proficiencies(
+        &mut self,
+        skills: &[Skill],
+        expertise: &[Skill],
+        saving_throws: &[Ability],
+    ) -> &mut Self {
+        for &skill in skills {
+            self.set_skill_proficiency(skill, Proficiency::Proficient);
+        }
+        for &skill in expertise {
+            self.set_skill_proficiency(skill, Proficiency::Expertise);
+        }
+        for &ability in saving_throws {
+            self.set_saving_throw_proficiency(ability, Proficiency::Proficient);
+        }
+        self
+    }
+
+    /// Total bonus for a saving throw, from the ability's modifier and any
+    /// proficiency.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores, Proficiency};
+    /// use rand::Rng;
+    ///
+    /// let mut scores: AbilityScores = rand::thread_rng().gen();
+    /// let modifier = scores.modifier(Ability::Wisdom);
+    ///
+    /// scores
+    ///     .set_proficiency_bonus(3)
+    ///     .set_saving_throw_proficiency(Ability::Wisdom, Proficiency::Proficient);
+    /// assert_eq!(scores.saving_throw_bonus(Ability::Wisdom), modifier + 3);
+    /// ```
+    #[must_use]
+    pub fn saving_throw_bonus(&self, ability: Ability) -> i8 {
+        self.modifier(ability)
+            + self
+                .saving_throw_proficiency(ability)
+                .bonus(self.proficiency_bonus)
+    }
+
+    /// Choose which of the given abilities should receive an increase.
+    ///
+    /// Will weight choices where possible towards applying increases to
+    /// ability scores that would cause in increase in the modifier.
+    ///
+    /// Returns `None` if every ability is already too high to take the
+    /// increase without going over 20.
+    fn choose_increase<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        abilities: impl IntoIterator<Item = Ability>,
+        increase: u8,
+    ) -> Option<Ability> {
+        // Filter out options that aren't valid choices
+        let all_ability_choices = abilities
+            .into_iter()
+            .filter(|&a| self.ability(a).score + increase <= MAX_INCREASED_SCORE)
             .collect::<Vec<_>>();
         // See if any would cause an increase in modifier score
         let optimal_ability_choices = all_ability_choices
@@ -291,16 +636,32 @@ impl AbilityScores {
             .collect::<Vec<_>>();
 
         // Choose from optimal choices if available, otherwise, choose any of them. Weighted by current modifier
-        let ability = if optimal_ability_choices.is_empty() {
+        let choice = if optimal_ability_choices.is_empty() {
             all_ability_choices.as_slice()
         } else {
             optimal_ability_choices.as_slice()
         }
-        .choose_exp_weighted(rng, |&a| self.modifier(a))
-        .unwrap();
+        .choose_exp_weighted(rng, |&a| self.modifier(a));
+
+        choice.ok().copied()
+    }
+
+    /// Choose a single racial increase.
+    ///
+    /// If every remaining ability is already too high to take the increase
+    /// without going over 20, the increase is skipped.
+    #[tracing::instrument(skip(rng))]
+    fn gen_racial_increase<R: Rng + ?Sized>(&mut self, rng: &mut R, increase: u8) -> &mut Self {
+        // Get all abilities that haven't already been chosen for racial increases.
+        // Ordered so that the same rng always chooses the same ability
+        let abilities = Ability::iter().collect::<BTreeSet<_>>();
+        let current_racial_increases = self.racial_increases.iter().map(|i| i.ability).collect();
+        let remaining_abilities = abilities.difference(&current_racial_increases);
 
-        self.racial_increases
-            .insert(AbilityScore::new(*ability, increase));
+        if let Some(ability) = self.choose_increase(rng, remaining_abilities.copied(), increase) {
+            self.racial_increases
+                .insert(AbilityScore::new(ability, increase));
+        }
 
         self
     }
@@ -319,9 +680,8 @@ impl AbilityScores {
     /// scores.gen_racial_increases(&mut rng, &[2, 1]);
     /// ```
     ///
-    /// # Panics
-    ///
-    /// Panics if an increase cannot be chosen, which shouldn't be possible.
+    /// Increases that can't be applied without taking a score over 20 are
+    /// skipped.
     #[tracing::instrument(skip(rng))]
     pub fn gen_racial_increases<R: Rng + ?Sized>(
         &mut self,
@@ -334,37 +694,222 @@ impl AbilityScores {
 
         self
     }
-}
 
-impl Distribution<AbilityScores> for Standard {
-    /// Generate base ability scores for a character.
-    /// Each ability is the result of rolling 4d6 and taking the top 3.
+    /// Apply racial increases to specific abilities, for races using the
+    /// [`RacialIncreaseRule::Fixed`] rules. Replaces any existing racial
+    /// increase for those abilities.
     ///
     /// ```
-    /// use abilities::AbilityScores;
+    /// use abilities::{Ability, AbilityScores};
+    ///
+    /// let mut scores: AbilityScores = serde_json::from_str(
+    ///     r#"{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 18}"#,
+    /// )?;
+    /// scores.add_racial_increases(&[(Ability::Strength, 2), (Ability::Charisma, 2)])?;
+    ///
+    /// assert_eq!(scores.score(Ability::Strength), 17);
+    /// assert_eq!(scores.score(Ability::Charisma), 20);
+    /// assert!(scores.add_racial_increases(&[(Ability::Charisma, 3)]).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if an increase would take a score over 20. None of the
+    /// increases are applied in that case.
+    #[tracing::instrument]
+    pub fn add_racial_increases(
+        &mut self,
+        increases: &[(Ability, u8)],
+    ) -> Result<&mut Self, AbilityScoresError> {
+        for &(ability, increase) in increases {
+            // Replaces any existing racial increase, so only the base score
+            // and improvements are kept
+            let score = self
+                .ability(ability)
+                .base
+                .saturating_add(self.improvement(ability))
+                .saturating_add(increase);
+            if score > MAX_INCREASED_SCORE {
+                return Err(AbilityScoresError::IncreaseOverMax { ability, score });
+            }
+        }
+
+        for &(ability, increase) in increases {
+            self.racial_increases.retain(|i| i.ability != ability);
+            if increase > 0 {
+                self.racial_increases
+                    .insert(AbilityScore::new(ability, increase));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Choose a single Ability Score Improvement, spending each point where
+    /// it would increase a modifier if possible. Points can go to the same
+    /// ability or to two different ones.
+    ///
+    /// Points that can't be spent without taking a score over 20 are
+    /// skipped.
+    #[tracing::instrument(skip(rng))]
+    fn gen_improvement<R: Rng + ?Sized>(&mut self, rng: &mut R) -> &mut Self {
+        for _ in 0..IMPROVEMENT_POINTS {
+            if let Some(ability) = self.choose_increase(rng, Ability::iter(), 1) {
+                *self.improvements.entry(ability).or_default() += 1;
+            }
+        }
+
+        self
+    }
+
+    /// Choose the Ability Score Improvements for a character gaining the
+    /// given levels, one for each of the levels in [`IMPROVEMENT_LEVELS`].
+    /// Use `..=level` for a new character starting at a higher level.
+    ///
+    /// Should be called after racial increases have been chosen.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores};
     /// use rand::Rng;
+    /// use strum::IntoEnumIterator;
     ///
-    /// let scores: AbilityScores = rand::thread_rng().gen();
+    /// let mut rng = rand::thread_rng();
+    /// let mut scores: AbilityScores = rng.gen();
+    /// scores.gen_racial_increases(&mut rng, &[2, 1]);
+    /// scores.gen_improvements(&mut rng, ..=8);
+    ///
+    /// assert!(Ability::iter().all(|a| scores.score(a) <= 20));
+    /// assert!(Ability::iter().map(|a| scores.improvement(a)).sum::<u8>() <= 4);
     /// ```
     #[tracing::instrument(skip(rng))]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AbilityScores {
-        let mut ability_scores = HashSet::new();
-        // Keep track of what abilities are still needed
-        let mut remaining_abilities: HashSet<Ability> = Ability::iter().collect();
-        // Roll all scores, put them in descending order
-        let mut scores = (0..remaining_abilities.len())
-            .map(|_| {
-                Die::D6
-                    .roll_multiple(rng, 4)
-                    .sorted()
-                    .rev()
-                    .take(3)
-                    .sum::<u8>()
-            })
+    pub fn gen_improvements<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        levels: impl RangeBounds<u8> + fmt::Debug,
+    ) -> &mut Self {
+        for _ in IMPROVEMENT_LEVELS.iter().filter(|l| levels.contains(l)) {
+            self.gen_improvement(rng);
+        }
+
+        self
+    }
+
+    /// Total increase to an ability from Ability Score Improvements
+    #[must_use]
+    pub fn improvement(&self, ability: Ability) -> u8 {
+        self.improvements.get(&ability).copied().unwrap_or_default()
+    }
+}
+
+impl AbilityScores {
+    /// Generate base ability scores for a character with the given method.
+    /// The highest scores go to the abilities most important to a randomly
+    /// chosen combat role, unless the method assigns scores in order.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores, GenerationMethod};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let scores = AbilityScores::gen_with_method(&mut rng, GenerationMethod::PointBuy);
+    /// assert!((8..=15).contains(&scores.score(Ability::Strength)));
+    /// ```
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_with_method<R: Rng + ?Sized>(rng: &mut R, method: GenerationMethod) -> Self {
+        Self::gen(rng, method, None)
+    }
+
+    /// Generate base ability scores for a character with the given method,
+    /// giving the highest scores to the abilities most important to the
+    /// given combat role.
+    ///
+    /// The role is ignored if the method assigns scores in order.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores, CombatRole, GenerationMethod};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let scores = AbilityScores::gen_with_role(
+    ///     &mut rng,
+    ///     GenerationMethod::StandardArray,
+    ///     CombatRole::FrontLine,
+    /// );
+    /// assert_eq!(scores.score(Ability::Strength), 15);
+    /// assert_eq!(scores.score(Ability::Constitution), 14);
+    /// ```
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_with_role<R: Rng + ?Sized>(
+        rng: &mut R,
+        method: GenerationMethod,
+        role: CombatRole,
+    ) -> Self {
+        Self::gen(rng, method, Some(role))
+    }
+
+    /// Generate base ability scores with the given method, for the given
+    /// combat role or a random one.
+    fn gen<R: Rng + ?Sized>(
+        rng: &mut R,
+        method: GenerationMethod,
+        role: Option<CombatRole>,
+    ) -> Self {
+        let four_d6 = Roll::new(4, Die::D6).drop_lowest(1);
+        let abilities = Ability::iter().len();
+
+        let scores = match method {
+            GenerationMethod::Roll => Self::roll_scores(rng, four_d6, abilities),
+            GenerationMethod::PointBuy => point_buy::gen_point_buy(rng),
+            GenerationMethod::StandardArray => STANDARD_ARRAY.to_vec(),
+            GenerationMethod::RollInOrder => {
+                return Self::new(
+                    Ability::iter()
+                        .map(|a| AbilityScore::new(a, Self::roll_score(rng, Roll::new(3, Die::D6))))
+                        .collect(),
+                )
+                .expect("every ability should have a generated score");
+            }
+            GenerationMethod::RollRerollOnes => {
+                Self::roll_scores(rng, four_d6.reroll_below(2), abilities)
+            }
+            GenerationMethod::RollSevenDropWorst => {
+                let mut scores = Self::roll_scores(rng, four_d6, abilities + 1);
+                scores.pop();
+                scores
+            }
+        };
+
+        Self::assign_by_combat_role(rng, scores, role)
+    }
+
+    /// Roll a single score
+    fn roll_score<R: Rng + ?Sized>(rng: &mut R, roll: Roll) -> u8 {
+        u8::try_from(roll.roll(rng).total()).unwrap()
+    }
+
+    /// Roll a number of scores, in descending order
+    fn roll_scores<R: Rng + ?Sized>(rng: &mut R, roll: Roll, amount: usize) -> Vec<u8> {
+        (0..amount)
+            .map(|_| Self::roll_score(rng, roll))
             .sorted()
-            .rev();
+            .rev()
+            .collect()
+    }
+
+    /// Assign scores to abilities based on a combat role, choosing a random
+    /// one if none is given. Expects scores in descending order.
+    fn assign_by_combat_role<R: Rng + ?Sized>(
+        rng: &mut R,
+        scores: Vec<u8>,
+        role: Option<CombatRole>,
+    ) -> Self {
+        let mut ability_scores = HashSet::new();
+        // Keep track of what abilities are still needed, in a consistent order
+        let mut remaining_abilities: BTreeSet<Ability> = Ability::iter().collect();
+        let mut scores = scores.into_iter();
         // Choose a combat role to base this on
-        let combat_role = rng.gen::<CombatRole>();
+        let combat_role = role.unwrap_or_else(|| rng.gen());
         // Choose offensive skill from options
         let offensive_ability = remaining_abilities
             .take(combat_role.offensive().choose(rng).unwrap())
@@ -381,19 +926,148 @@ impl Distribution<AbilityScores> for Standard {
             ability_scores.insert(AbilityScore::new(ability, score));
         }
 
-        AbilityScores::new(ability_scores)
+        Self {
+            combat_role: Some(combat_role),
+            ..Self::new(ability_scores).expect("every ability should have a generated score")
+        }
     }
 }
 
+impl Distribution<AbilityScores> for Standard {
+    /// Generate base ability scores for a character.
+    /// Each ability is the result of rolling 4d6 and taking the top 3.
+    ///
+    /// ```
+    /// use abilities::AbilityScores;
+    /// use rand::Rng;
+    ///
+    /// let scores: AbilityScores = rand::thread_rng().gen();
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> AbilityScores {
+        AbilityScores::gen_with_method(rng, GenerationMethod::Roll)
+    }
+}
+
+impl TryFrom<BTreeMap<Ability, u8>> for AbilityScores {
+    type Error = AbilityScoresError;
+
+    fn try_from(scores: BTreeMap<Ability, u8>) -> Result<Self, Self::Error> {
+        Self::new(
+            scores
+                .into_iter()
+                .map(|(ability, score)| AbilityScore::new(ability, score))
+                .collect(),
+        )
+    }
+}
+
+/// Errors caused by an invalid set of ability scores
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum AbilityScoresError {
+    /// No score was provided for an ability
+    #[error("Missing a score for {0}. Please provide a score for every ability.")]
+    MissingAbility(Ability),
+    /// More than one score was provided for an ability
+    #[error("More than one score was provided for {0}.")]
+    DuplicateAbility(Ability),
+    /// A score is outside of the valid range
+    #[error(
+        "{ability} has a score of {score}, but scores must be between {MIN_SCORE} and {MAX_SCORE}."
+    )]
+    ScoreOutOfRange {
+        /// Ability the score was for
+        ability: Ability,
+        /// The invalid score
+        score: u8,
+    },
+    /// An increase would take a score over the maximum
+    #[error("Increases would take {ability} to {score}, but increases can't take a score above {MAX_INCREASED_SCORE}.")]
+    IncreaseOverMax {
+        /// Ability being increased
+        ability: Ability,
+        /// Score the ability would have after the increase
+        score: u8,
+    },
+}
+
 /// Serializable version of the ability scores (usually for character sheets)
-/// A `BTreeMap` so that it stays in the same order
 #[derive(Clone, Debug, Serialize)]
-#[serde(transparent)]
-struct AbilityScoreStats(BTreeMap<Ability, AbilityScoreTotal>);
+struct AbilityScoreStats {
+    /// A `BTreeMap` so that it stays in the same order
+    #[serde(flatten)]
+    scores: BTreeMap<Ability, AbilityScoreTotal>,
+    /// Combat role the scores were assigned for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    combat_role: Option<CombatRole>,
+    /// Bonus added to proficient skills and saving throws. Left out before V2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proficiency_bonus: Option<i8>,
+    /// Bonus for every saving throw. Left out until the character has a
+    /// proficiency, since until then every bonus is just the modifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    saving_throws: Option<BTreeMap<Ability, ProficiencyBonus>>,
+    /// Bonus for every skill. Left out until the character has a
+    /// proficiency, since until then every bonus is just the modifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skills: Option<BTreeMap<Skill, ProficiencyBonus>>,
+}
+
+/// Serializable bonus for a skill or saving throw
+#[derive(Clone, Copy, Debug, Serialize)]
+struct ProficiencyBonus {
+    /// Proficiency the character has
+    proficiency: Proficiency,
+    /// Total bonus added to the roll
+    bonus: i8,
+}
 
 impl From<AbilityScores> for AbilityScoreStats {
     fn from(scores: AbilityScores) -> Self {
-        Self(Ability::iter().map(|a| (a, scores.ability(a))).collect())
+        // Improvements and proficiencies were only reported from V2 onward
+        let reports_levels = scores.version > GeneratorVersion::V1;
+        let proficient = reports_levels
+            && scores
+                .skill_proficiencies
+                .values()
+                .chain(scores.saving_throw_proficiencies.values())
+                .any(|&p| p != Proficiency::None);
+
+        Self {
+            scores: Ability::iter()
+                .map(|a| {
+                    let mut total = scores.ability(a);
+                    if !reports_levels {
+                        total.improvement = None;
+                    }
+                    (a, total)
+                })
+                .collect(),
+            combat_role: scores.combat_role,
+            proficiency_bonus: reports_levels.then_some(scores.proficiency_bonus),
+            saving_throws: proficient.then(|| {
+                Ability::iter()
+                    .map(|a| {
+                        let bonus = ProficiencyBonus {
+                            proficiency: scores.saving_throw_proficiency(a),
+                            bonus: scores.saving_throw_bonus(a),
+                        };
+                        (a, bonus)
+                    })
+                    .collect()
+            }),
+            skills: proficient.then(|| {
+                Skill::iter()
+                    .map(|s| {
+                        let bonus = ProficiencyBonus {
+                            proficiency: scores.skill_proficiency(s),
+                            bonus: scores.skill_bonus(s),
+                        };
+                        (s, bonus)
+                    })
+                    .collect()
+            }),
+        }
     }
 }
 
@@ -403,26 +1077,26 @@ mod test {
 
     #[test]
     fn modifier_logic() {
-        assert_eq!(AbilityScoreTotal::new(0, 0).modifier, -5);
-        assert_eq!(AbilityScoreTotal::new(1, 0).modifier, -5);
-        assert_eq!(AbilityScoreTotal::new(2, 0).modifier, -4);
-        assert_eq!(AbilityScoreTotal::new(3, 0).modifier, -4);
-        assert_eq!(AbilityScoreTotal::new(4, 0).modifier, -3);
-        assert_eq!(AbilityScoreTotal::new(5, 0).modifier, -3);
-        assert_eq!(AbilityScoreTotal::new(6, 0).modifier, -2);
-        assert_eq!(AbilityScoreTotal::new(7, 0).modifier, -2);
-        assert_eq!(AbilityScoreTotal::new(8, 0).modifier, -1);
-        assert_eq!(AbilityScoreTotal::new(9, 0).modifier, -1);
-        assert_eq!(AbilityScoreTotal::new(10, 0).modifier, 0);
-        assert_eq!(AbilityScoreTotal::new(11, 0).modifier, 0);
-        assert_eq!(AbilityScoreTotal::new(12, 0).modifier, 1);
-        assert_eq!(AbilityScoreTotal::new(13, 0).modifier, 1);
-        assert_eq!(AbilityScoreTotal::new(14, 0).modifier, 2);
-        assert_eq!(AbilityScoreTotal::new(15, 0).modifier, 2);
-        assert_eq!(AbilityScoreTotal::new(16, 0).modifier, 3);
-        assert_eq!(AbilityScoreTotal::new(17, 0).modifier, 3);
-        assert_eq!(AbilityScoreTotal::new(18, 0).modifier, 4);
-        assert_eq!(AbilityScoreTotal::new(19, 0).modifier, 4);
-        assert_eq!(AbilityScoreTotal::new(20, 0).modifier, 5);
+        assert_eq!(AbilityScoreTotal::new(0, 0, 0).modifier, -5);
+        assert_eq!(AbilityScoreTotal::new(1, 0, 0).modifier, -5);
+        assert_eq!(AbilityScoreTotal::new(2, 0, 0).modifier, -4);
+        assert_eq!(AbilityScoreTotal::new(3, 0, 0).modifier, -4);
+        assert_eq!(AbilityScoreTotal::new(4, 0, 0).modifier, -3);
+        assert_eq!(AbilityScoreTotal::new(5, 0, 0).modifier, -3);
+        assert_eq!(AbilityScoreTotal::new(6, 0, 0).modifier, -2);
+        assert_eq!(AbilityScoreTotal::new(7, 0, 0).modifier, -2);
+        assert_eq!(AbilityScoreTotal::new(8, 0, 0).modifier, -1);
+        assert_eq!(AbilityScoreTotal::new(9, 0, 0).modifier, -1);
+        assert_eq!(AbilityScoreTotal::new(10, 0, 0).modifier, 0);
+        assert_eq!(AbilityScoreTotal::new(11, 0, 0).modifier, 0);
+        assert_eq!(AbilityScoreTotal::new(12, 0, 0).modifier, 1);
+        assert_eq!(AbilityScoreTotal::new(13, 0, 0).modifier, 1);
+        assert_eq!(AbilityScoreTotal::new(14, 0, 0).modifier, 2);
+        assert_eq!(AbilityScoreTotal::new(15, 0, 0).modifier, 2);
+        assert_eq!(AbilityScoreTotal::new(16, 0, 0).modifier, 3);
+        assert_eq!(AbilityScoreTotal::new(17, 0, 0).modifier, 3);
+        assert_eq!(AbilityScoreTotal::new(18, 0, 0).modifier, 4);
+        assert_eq!(AbilityScoreTotal::new(19, 0, 0).modifier, 4);
+        assert_eq!(AbilityScoreTotal::new(20, 0, 0).modifier, 5);
     }
 }
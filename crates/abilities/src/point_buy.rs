@@ -0,0 +1,87 @@
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+use thiserror::Error;
+
+/// Number of points available to spend with the point buy method
+pub const POINT_BUY_BUDGET: u8 = 27;
+
+/// Lowest score that can be bought
+const MIN_SCORE: u8 = 8;
+
+/// Highest score that can be bought
+const MAX_SCORE: u8 = 15;
+
+/// Cost of buying a given score with the point buy method
+///
+/// ```
+/// use abilities::point_buy_cost;
+///
+/// assert_eq!(point_buy_cost(8), Ok(0));
+/// assert_eq!(point_buy_cost(13), Ok(5));
+/// assert_eq!(point_buy_cost(15), Ok(9));
+/// assert!(point_buy_cost(16).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will error if the score can't be bought, because it is below 8 or above 15.
+pub const fn point_buy_cost(score: u8) -> Result<u8, PointBuyError> {
+    match score {
+        MIN_SCORE..=13 => Ok(score - MIN_SCORE),
+        14 => Ok(7),
+        MAX_SCORE => Ok(9),
+        _ => Err(PointBuyError::ScoreOutOfRange(score)),
+    }
+}
+
+/// Check that a set of scores is a legal point buy, returning the total
+/// number of points spent.
+///
+/// ```
+/// use abilities::validate_point_buy;
+///
+/// assert_eq!(validate_point_buy([15, 15, 15, 8, 8, 8]), Ok(27));
+/// assert!(validate_point_buy([15, 15, 15, 15, 8, 8]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Will error if any score can't be bought, or if more points are spent than
+/// the budget allows.
+pub fn validate_point_buy(scores: impl IntoIterator<Item = u8>) -> Result<u8, PointBuyError> {
+    let spent = scores.into_iter().try_fold(0u8, |spent, score| {
+        Ok::<_, PointBuyError>(spent.saturating_add(point_buy_cost(score)?))
+    })?;
+
+    if spent > POINT_BUY_BUDGET {
+        Err(PointBuyError::Overspent(spent))
+    } else {
+        Ok(spent)
+    }
+}
+
+/// Choose a random set of six scores that spends the entire point buy budget,
+/// in descending order.
+pub(crate) fn gen_point_buy<R: Rng + ?Sized>(rng: &mut R) -> Vec<u8> {
+    let options = (MIN_SCORE..=MAX_SCORE)
+        .rev()
+        .combinations_with_replacement(6)
+        .filter(|scores| validate_point_buy(scores.iter().copied()) == Ok(POINT_BUY_BUDGET))
+        .collect::<Vec<_>>();
+
+    options
+        .choose(rng)
+        .expect("there should always be a way to spend all points")
+        .clone()
+}
+
+/// Errors caused by an illegal point buy
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum PointBuyError {
+    /// Score can't be bought with points
+    #[error("A score of {0} can't be bought with points. Scores must be between {MIN_SCORE} and {MAX_SCORE} before racial increases.")]
+    ScoreOutOfRange(u8),
+    /// More points spent than are available
+    #[error("{0} points were spent, but only {POINT_BUY_BUDGET} are available.")]
+    Overspent(u8),
+}
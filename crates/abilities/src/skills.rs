@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+use crate::{Ability, MAX_LEVEL};
+
+/// Proficiency bonus for a 1st level character
+pub const DEFAULT_PROFICIENCY_BONUS: i8 = 2;
+
+/// Proficiency bonus for a character of the given level
+///
+/// ```
+/// use abilities::proficiency_bonus;
+///
+/// assert_eq!(proficiency_bonus(1), 2);
+/// assert_eq!(proficiency_bonus(5), 3);
+/// assert_eq!(proficiency_bonus(20), 6);
+/// ```
+///
+/// # Panics
+///
+/// Won't panic, because levels are clamped between 1 and 20 first
+#[must_use]
+pub fn proficiency_bonus(level: u8) -> i8 {
+    let increases = (level.clamp(1, MAX_LEVEL) - 1) / 4;
+    DEFAULT_PROFICIENCY_BONUS + i8::try_from(increases).unwrap()
+}
+
+/// Available skills, each governed by a single ability.
+/// Ordered alphabetically, the same as a character sheet.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Skill {
+    /// Performing acrobatic stunts and keeping your balance
+    Acrobatics,
+    /// Calming and training animals
+    #[strum(serialize = "Animal Handling")]
+    AnimalHandling,
+    /// Recalling lore about spells, magic items, and the planes
+    Arcana,
+    /// Climbing, jumping, and swimming
+    Athletics,
+    /// Misleading others through words or actions
+    Deception,
+    /// Recalling lore about historical events and people
+    History,
+    /// Reading the true intentions of others
+    Insight,
+    /// Influencing others through threats and hostility
+    Intimidation,
+    /// Searching for clues and making deductions
+    Investigation,
+    /// Stabilizing the dying and diagnosing illnesses
+    Medicine,
+    /// Recalling lore about terrain, plants, and animals
+    Nature,
+    /// Spotting, hearing, or otherwise noticing things
+    Perception,
+    /// Delighting an audience with music, dance, or acting
+    Performance,
+    /// Influencing others with tact and good nature
+    Persuasion,
+    /// Recalling lore about deities, rites, and religious hierarchies
+    Religion,
+    /// Pickpocketing, planting things on others, and other manual trickery
+    #[strum(serialize = "Sleight of Hand")]
+    SleightOfHand,
+    /// Hiding and moving without being noticed
+    Stealth,
+    /// Following tracks, hunting, and navigating the wilds
+    Survival,
+}
+
+impl Skill {
+    /// Ability that governs this skill
+    ///
+    /// ```
+    /// use abilities::{Ability, Skill};
+    ///
+    /// assert_eq!(Skill::Athletics.ability(), Ability::Strength);
+    /// assert_eq!(Skill::Stealth.ability(), Ability::Dexterity);
+    /// ```
+    #[must_use]
+    pub const fn ability(self) -> Ability {
+        match self {
+            Self::Athletics => Ability::Strength,
+            Self::Acrobatics | Self::SleightOfHand | Self::Stealth => Ability::Dexterity,
+            Self::Arcana | Self::History | Self::Investigation | Self::Nature | Self::Religion => {
+                Ability::Intelligence
+            }
+            Self::AnimalHandling
+            | Self::Insight
+            | Self::Medicine
+            | Self::Perception
+            | Self::Survival => Ability::Wisdom,
+            Self::Deception | Self::Intimidation | Self::Performance | Self::Persuasion => {
+                Ability::Charisma
+            }
+        }
+    }
+}
+
+/// How much of the proficiency bonus is added to a roll
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    EnumIter,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Proficiency {
+    /// Proficiency bonus isn't added
+    #[default]
+    None,
+    /// Proficiency bonus is added
+    Proficient,
+    /// Proficiency bonus is added twice
+    Expertise,
+}
+
+impl Proficiency {
+    /// Amount added to a roll for a given proficiency bonus
+    ///
+    /// ```
+    /// use abilities::Proficiency;
+    ///
+    /// assert_eq!(Proficiency::None.bonus(3), 0);
+    /// assert_eq!(Proficiency::Proficient.bonus(3), 3);
+    /// assert_eq!(Proficiency::Expertise.bonus(3), 6);
+    /// ```
+    #[must_use]
+    pub const fn bonus(self, proficiency_bonus: i8) -> i8 {
+        match self {
+            Self::None => 0,
+            Self::Proficient => proficiency_bonus,
+            Self::Expertise => proficiency_bonus * 2,
+        }
+    }
+}
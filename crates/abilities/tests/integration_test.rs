@@ -1,3 +1,4 @@
+//! Integration tests for the `abilities` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -10,9 +11,15 @@
     unused
 )]
 
-use abilities::{Ability, AbilityScores};
+use std::collections::HashSet;
+
+use abilities::{
+    point_buy_cost, validate_point_buy, Ability, AbilityScore, AbilityScores, AbilityScoresError,
+    CombatRole, GenerationMethod, PointBuyError, Proficiency, Skill,
+};
 use itertools::{repeat_n, Itertools};
 use rand::Rng;
+use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
 use serde_json::json;
 use statrs::statistics::Statistics;
 use strum::IntoEnumIterator;
@@ -58,12 +65,114 @@ fn serialization() {
             json!({
                 "base": scores.score(ability),
                 "racial_increase": 0,
+                "improvement": 0,
                 "score": scores.score(ability),
                 "modifier": scores.modifier(ability),
             }),
             serialized[ability.to_string()]
         );
     }
+    assert_eq!(json!(scores.combat_role()), serialized["combat_role"]);
+}
+
+#[test]
+fn skills_and_saving_throws() {
+    let mut scores: AbilityScores = rand_utils::rng_from_entropy().gen();
+
+    // Without proficiency, bonuses are just the modifier
+    for skill in Skill::iter() {
+        assert_eq!(scores.skill_bonus(skill), scores.modifier(skill.ability()));
+    }
+    for ability in Ability::iter() {
+        assert_eq!(scores.saving_throw_bonus(ability), scores.modifier(ability));
+    }
+
+    scores
+        .set_proficiency_bonus(4)
+        .set_skill_proficiency(Skill::Arcana, Proficiency::Proficient)
+        .set_skill_proficiency(Skill::Stealth, Proficiency::Expertise)
+        .set_saving_throw_proficiency(Ability::Constitution, Proficiency::Proficient);
+
+    let intelligence = scores.modifier(Ability::Intelligence);
+    let dexterity = scores.modifier(Ability::Dexterity);
+    let constitution = scores.modifier(Ability::Constitution);
+    assert_eq!(scores.skill_bonus(Skill::Arcana), intelligence + 4);
+    assert_eq!(scores.skill_bonus(Skill::Stealth), dexterity + 8);
+    assert_eq!(scores.skill_bonus(Skill::History), intelligence);
+    assert_eq!(
+        scores.saving_throw_bonus(Ability::Constitution),
+        constitution + 4
+    );
+
+    let serialized = json!(scores);
+    assert_eq!(serialized["proficiency_bonus"], 4);
+    assert_eq!(
+        serialized["skills"]["stealth"],
+        json!({ "proficiency": "expertise", "bonus": dexterity + 8 })
+    );
+    assert_eq!(
+        serialized["skills"]["sleight-of-hand"],
+        json!({ "proficiency": "none", "bonus": dexterity })
+    );
+    assert_eq!(
+        serialized["saving_throws"]["CON"],
+        json!({ "proficiency": "proficient", "bonus": constitution + 4 })
+    );
+    assert_eq!(serialized["skills"].as_object().unwrap().len(), 18);
+    assert_eq!(serialized["saving_throws"].as_object().unwrap().len(), 6);
+}
+
+#[test]
+fn skills_are_left_out_without_proficiencies() {
+    let mut scores = rand_utils::rng_from_entropy().gen::<AbilityScores>();
+    assert!(json!(scores).get("skills").is_none());
+    assert!(json!(scores).get("saving_throws").is_none());
+
+    scores.set_saving_throw_proficiency(Ability::Wisdom, Proficiency::Proficient);
+    assert_eq!(json!(scores)["skills"].as_object().unwrap().len(), 18);
+    assert_eq!(json!(scores)["saving_throws"].as_object().unwrap().len(), 6);
+}
+
+#[test]
+fn improvements_and_proficiencies_are_left_out_of_v1() {
+    let mut scores = rand_utils::rng_from_entropy().gen::<AbilityScores>();
+    scores.set_skill_proficiency(Skill::Stealth, Proficiency::Proficient);
+
+    let serialized = json!(scores.for_version(GeneratorVersion::V1));
+    assert!(serialized.get("proficiency_bonus").is_none());
+    assert!(serialized.get("skills").is_none());
+    assert!(serialized.get("saving_throws").is_none());
+    assert!(Ability::iter().all(|a| serialized[a.to_string()].get("improvement").is_none()));
+}
+
+#[test]
+fn combat_roles() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for role in CombatRole::iter() {
+        for method in GenerationMethod::iter().filter(|&m| m != GenerationMethod::RollInOrder) {
+            let scores = AbilityScores::gen_with_role(&mut rng, method, role);
+            assert_eq!(scores.combat_role(), Some(role));
+            assert_eq!(json!(scores)["combat_role"], json!(role));
+        }
+        // Displayed the same way it is serialized and parsed from queries
+        assert_eq!(json!(role), role.to_string());
+    }
+
+    // Standard array puts the highest scores where the role needs them
+    let scores = AbilityScores::gen_with_role(
+        &mut rng,
+        GenerationMethod::StandardArray,
+        CombatRole::Marksman,
+    );
+    assert_eq!(scores.score(Ability::Dexterity), 15);
+    assert_eq!(scores.score(Ability::Wisdom), 14);
+    assert_eq!(json!(scores)["combat_role"], "marksman");
+
+    // Scores assigned in order have no role
+    let scores = AbilityScores::gen_with_method(&mut rng, GenerationMethod::RollInOrder);
+    assert_eq!(scores.combat_role(), None);
+    assert!(json!(scores).get("combat_role").is_none());
 }
 
 #[test]
@@ -90,3 +199,237 @@ fn racial_increases() {
     // Check that none are above 20
     assert!(Ability::iter().map(|a| scores.score(a)).all(|s| s <= 20));
 }
+
+#[test]
+fn point_buy_ability_scores() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for _ in 0..100 {
+        let scores = AbilityScores::gen_with_method(&mut rng, GenerationMethod::PointBuy);
+        let base = Ability::iter().map(|a| scores.score(a));
+        assert!(base.clone().all(|s| (8..=15).contains(&s)));
+        assert_eq!(validate_point_buy(base), Ok(27));
+    }
+}
+
+#[test]
+fn alternative_methods() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for _ in 0..100 {
+        let scores = AbilityScores::gen_with_method(&mut rng, GenerationMethod::StandardArray);
+        let base = Ability::iter()
+            .map(|a| scores.score(a))
+            .sorted()
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(base, [15, 14, 13, 12, 10, 8]);
+
+        for method in [
+            GenerationMethod::RollInOrder,
+            GenerationMethod::RollRerollOnes,
+            GenerationMethod::RollSevenDropWorst,
+        ] {
+            let scores = AbilityScores::gen_with_method(&mut rng, method);
+            assert!(Ability::iter().all(|a| (3..=18).contains(&scores.score(a))));
+        }
+    }
+
+    // Rerolling 1s and dropping the worst score should both raise the average
+    let mut average = |method| {
+        (0..500)
+            .flat_map(|_| {
+                let scores = AbilityScores::gen_with_method(&mut rng, method);
+                Ability::iter()
+                    .map(|a| f64::from(scores.score(a)))
+                    .collect::<Vec<_>>()
+            })
+            .mean()
+    };
+    let in_order = average(GenerationMethod::RollInOrder);
+    let roll = average(GenerationMethod::Roll);
+    assert!(in_order < roll);
+    assert!(roll < average(GenerationMethod::RollRerollOnes));
+    assert!(roll < average(GenerationMethod::RollSevenDropWorst));
+}
+
+#[test]
+fn point_buy_validation() {
+    let costs = (8..=15)
+        .map(|s| point_buy_cost(s).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(costs, [0, 1, 2, 3, 4, 5, 7, 9]);
+
+    assert_eq!(validate_point_buy([15, 14, 13, 12, 10, 8]), Ok(27));
+    assert_eq!(validate_point_buy([10; 6]), Ok(12));
+    assert_eq!(
+        validate_point_buy([15, 15, 15, 15, 8, 8]),
+        Err(PointBuyError::Overspent(36))
+    );
+    assert_eq!(
+        validate_point_buy([16, 8, 8, 8, 8, 8]),
+        Err(PointBuyError::ScoreOutOfRange(16))
+    );
+    assert_eq!(
+        validate_point_buy([7, 8, 8, 8, 8, 8]),
+        Err(PointBuyError::ScoreOutOfRange(7))
+    );
+}
+
+#[test]
+fn user_supplied_scores() {
+    let scores: AbilityScores = serde_json::from_value(json!({
+        "STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8
+    }))
+    .unwrap();
+
+    assert_eq!(scores.score(Ability::Strength), 15);
+    assert_eq!(scores.score(Ability::Charisma), 8);
+    assert_eq!(scores.modifier(Ability::Dexterity), 2);
+
+    for (value, error) in [
+        (
+            json!({ "STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10 }),
+            AbilityScoresError::MissingAbility(Ability::Charisma),
+        ),
+        (
+            json!({ "STR": 31, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8 }),
+            AbilityScoresError::ScoreOutOfRange {
+                ability: Ability::Strength,
+                score: 31,
+            },
+        ),
+        (
+            json!({ "STR": 15, "DEX": 14, "CON": 0, "INT": 12, "WIS": 10, "CHA": 8 }),
+            AbilityScoresError::ScoreOutOfRange {
+                ability: Ability::Constitution,
+                score: 0,
+            },
+        ),
+    ] {
+        let message = serde_json::from_value::<AbilityScores>(value)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(message, error.to_string());
+    }
+
+    // Two different scores for the same ability
+    let duplicate = Ability::iter()
+        .map(|a| AbilityScore::new(a, 10))
+        .chain([AbilityScore::new(Ability::Wisdom, 12)])
+        .collect::<HashSet<_>>();
+    assert_eq!(
+        AbilityScores::new(duplicate).unwrap_err(),
+        AbilityScoresError::DuplicateAbility(Ability::Wisdom)
+    );
+}
+
+#[test]
+fn ability_score_improvements() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for _ in 0..100 {
+        let mut scores: AbilityScores = rng.gen();
+        scores.gen_racial_increases(&mut rng, &[2, 1]);
+        let before = Ability::iter()
+            .map(|a| (a, scores.score(a), scores.modifier(a)))
+            .collect::<Vec<_>>();
+
+        // No improvements before 4th level
+        scores.gen_improvements(&mut rng, ..=3);
+        assert!(Ability::iter().all(|a| scores.improvement(a) == 0));
+
+        scores.gen_improvements(&mut rng, 4..=7);
+        let total = Ability::iter().map(|a| scores.improvement(a)).sum::<u8>();
+        let modifier_increases = before
+            .iter()
+            .map(|&(a, _, modifier)| scores.modifier(a) - modifier)
+            .sum::<i8>();
+        // Both points are spent unless everything is capped
+        if before.iter().all(|&(_, score, _)| score < 19) {
+            assert_eq!(total, 2);
+        }
+        // Points go towards raising modifiers wherever possible
+        if before
+            .iter()
+            .filter(|&&(_, score, _)| score % 2 == 1 && score < 20)
+            .count()
+            >= 2
+        {
+            assert_eq!(modifier_increases, 2);
+        } else if total == 2 {
+            assert!(modifier_increases >= 1);
+        }
+        assert!(Ability::iter().all(|a| scores.score(a) <= 20));
+        assert_eq!(
+            json!(scores)["STR"]["improvement"],
+            scores.improvement(Ability::Strength)
+        );
+    }
+
+    // Points that would take a score over 20 are skipped
+    let mut scores = AbilityScores::try_from(
+        Ability::iter()
+            .map(|a| (a, 19))
+            .collect::<std::collections::BTreeMap<_, _>>(),
+    )
+    .unwrap();
+    scores.gen_improvements(&mut rng, ..=20);
+    assert!(Ability::iter().all(|a| scores.score(a) == 20));
+    assert_eq!(
+        Ability::iter().map(|a| scores.improvement(a)).sum::<u8>(),
+        6
+    );
+}
+
+#[test]
+fn racial_increases_are_skipped_at_the_cap() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let mut scores =
+        AbilityScores::new(Ability::iter().map(|a| AbilityScore::new(a, 20)).collect()).unwrap();
+
+    scores.gen_racial_increases(&mut rng, &[2, 1]);
+    assert!(Ability::iter().all(|a| scores.score(a) == 20));
+}
+
+#[test]
+fn fixed_racial_increases_over_the_cap_are_rejected() {
+    let mut scores =
+        AbilityScores::new(Ability::iter().map(|a| AbilityScore::new(a, 19)).collect()).unwrap();
+
+    assert_eq!(
+        scores
+            .add_racial_increases(&[(Ability::Strength, 1), (Ability::Charisma, 2)])
+            .unwrap_err(),
+        AbilityScoresError::IncreaseOverMax {
+            ability: Ability::Charisma,
+            score: 21
+        }
+    );
+    // Nothing is applied if any increase is rejected
+    assert_eq!(scores.score(Ability::Strength), 19);
+
+    scores
+        .add_racial_increases(&[(Ability::Strength, 1)])
+        .unwrap();
+    assert_eq!(scores.score(Ability::Strength), 20);
+}
+
+#[test]
+fn seeded_ability_scores_are_stable() {
+    // V2 started reporting the combat role
+    assert_golden_versions(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"),
+        &[GeneratorVersion::V1, GeneratorVersion::V2],
+        |version| {
+            (0..10)
+                .map(|seed| {
+                    let mut rng = version.rng_from_seed(seed);
+                    let mut scores = rng.gen::<AbilityScores>();
+                    scores.gen_racial_increases(&mut rng, &[2, 1]);
+                    scores.for_version(version)
+                })
+                .collect::<Vec<_>>()
+        },
+    );
+}
@@ -13,14 +13,20 @@
     unused
 )]
 
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, str::FromStr};
 
 use rand::Rng;
 use rand_utils::SliceExpRandom;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator};
+use thiserror::Error;
 
-#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq, Serialize)]
+pub use crate::option::AlignmentOption;
+
+mod option;
+
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String")]
 /// attitudes toward society and order
 pub enum Attitude {
     /// little regard for what others expect, creatures follow their whims
@@ -60,7 +66,38 @@ impl Attitude {
     }
 }
 
-#[derive(Clone, Copy, Debug, Display, EnumIter, Eq, PartialEq, Serialize)]
+impl FromStr for Attitude {
+    type Err = ParseAlignmentError;
+
+    /// Parse an attitude from its name or first letter, ignoring case
+    ///
+    /// ```
+    /// use alignments::Attitude;
+    ///
+    /// assert_eq!("Lawful".parse(), Ok(Attitude::Lawful));
+    /// assert_eq!("c".parse(), Ok(Attitude::Chaotic));
+    /// assert!("Good".parse::<Attitude>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "chaotic" | "c" => Ok(Self::Chaotic),
+            "lawful" | "l" => Ok(Self::Lawful),
+            "neutral" | "n" => Ok(Self::Neutral),
+            _ => Err(ParseAlignmentError::Attitude(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Attitude {
+    type Error = ParseAlignmentError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String")]
 /// Character's morality, view toward good and bad
 pub enum Morality {
     /// methodically take what they want, do whatever they can get away with, or act with arbitrary violence, spurred by their greed, hatred, or bloodlust
@@ -100,13 +137,43 @@ impl Morality {
     }
 }
 
+impl FromStr for Morality {
+    type Err = ParseAlignmentError;
+
+    /// Parse a morality from its name or first letter, ignoring case
+    ///
+    /// ```
+    /// use alignments::Morality;
+    ///
+    /// assert_eq!("Good".parse(), Ok(Morality::Good));
+    /// assert_eq!("e".parse(), Ok(Morality::Evil));
+    /// assert!("Lawful".parse::<Morality>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "evil" | "e" => Ok(Self::Evil),
+            "good" | "g" => Ok(Self::Good),
+            "neutral" | "n" => Ok(Self::Neutral),
+            _ => Err(ParseAlignmentError::Morality(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Morality {
+    type Error = ParseAlignmentError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 /// A typical creature in the game world has an alignment, which broadly
 /// describes its moral and personal attitudes. Alignment is a combination of
 /// two factors: one identifies morality (good, evil, or neutral), and the
 /// other describes attitudes toward society and order (lawful, chaotic, or
 /// neutral). Thus, nine distinct alignments define the possible combinations.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
-#[serde(into = "String")]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
 pub struct Alignment {
     /// lawful, chaotic, or neutral
     attitude: Attitude,
@@ -172,6 +239,54 @@ impl Alignment {
         Self { attitude, morality }
     }
 
+    /// Probability of [`Alignment::gen`] choosing each of the nine
+    /// alignments with the given influences.
+    ///
+    /// ```
+    /// use alignments::{Alignment, Attitude, Morality};
+    ///
+    /// let probabilities = Alignment::probabilities(&[Attitude::Lawful], &[Morality::Good]);
+    /// let total = probabilities.iter().map(|(_, p)| p).sum::<f64>();
+    /// assert!((total - 1.0).abs() < 1e-9);
+    ///
+    /// let (most_likely, _) = probabilities
+    ///     .iter()
+    ///     .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    ///     .unwrap();
+    /// assert_eq!(*most_likely, Alignment::LAWFUL_GOOD);
+    /// ```
+    #[must_use]
+    pub fn probabilities(
+        attitude_influences: &[Attitude],
+        morality_influences: &[Morality],
+    ) -> Vec<(Self, f64)> {
+        let attitudes = Attitude::iter().zip(rand_utils::exp_probabilities(
+            &Attitude::iter()
+                .map(|a| a.weight(attitude_influences))
+                .collect::<Vec<_>>(),
+        ));
+        let moralities = Morality::iter()
+            .zip(rand_utils::exp_probabilities(
+                &Morality::iter()
+                    .map(|m| m.weight(morality_influences))
+                    .collect::<Vec<_>>(),
+            ))
+            .collect::<Vec<_>>();
+
+        attitudes
+            .flat_map(|(attitude, attitude_probability)| {
+                moralities
+                    .iter()
+                    .map(move |&(morality, morality_probability)| {
+                        (
+                            Self::new(attitude, morality),
+                            attitude_probability * morality_probability,
+                        )
+                    })
+            })
+            .collect()
+    }
+
     /// Weight of a particular alignment based on influences.
     /// Useful for comparing things like deities.
     #[must_use]
@@ -202,6 +317,84 @@ impl From<Alignment> for String {
     }
 }
 
+impl FromStr for Alignment {
+    type Err = ParseAlignmentError;
+
+    /// Parse an alignment from its full name or abbreviation, ignoring case.
+    ///
+    /// ```
+    /// use alignments::Alignment;
+    ///
+    /// assert_eq!("Chaotic Good".parse(), Ok(Alignment::CHAOTIC_GOOD));
+    /// assert_eq!("CG".parse(), Ok(Alignment::CHAOTIC_GOOD));
+    /// assert_eq!("lawful-evil".parse(), Ok(Alignment::LAWFUL_EVIL));
+    /// assert_eq!("N".parse(), Ok(Alignment::NEUTRAL));
+    /// assert_eq!("True Neutral".parse(), Ok(Alignment::NEUTRAL));
+    /// assert!("Good Lawful".parse::<Alignment>().is_err());
+    /// assert!("True Good".parse::<Alignment>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAlignmentError::Alignment(s.to_string());
+        let words = s
+            .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>();
+
+        match words.as_slice() {
+            [word] if word.eq_ignore_ascii_case("n") || word.eq_ignore_ascii_case("neutral") => {
+                Ok(Self::NEUTRAL)
+            }
+            // Abbreviations, such as CG
+            [word] if word.len() == 2 && word.is_ascii() => {
+                let (attitude, morality) = word.split_at(1);
+                Ok(Self::new(
+                    attitude.parse().map_err(|_| invalid())?,
+                    morality.parse().map_err(|_| invalid())?,
+                ))
+            }
+            [first, second]
+                if first.eq_ignore_ascii_case("true") && second.eq_ignore_ascii_case("neutral") =>
+            {
+                Ok(Self::NEUTRAL)
+            }
+            [attitude, morality] => Ok(Self::new(
+                attitude.parse().map_err(|_| invalid())?,
+                morality.parse().map_err(|_| invalid())?,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl TryFrom<String> for Alignment {
+    type Error = ParseAlignmentError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Errors caused by parsing an invalid alignment
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseAlignmentError {
+    /// Not a valid attitude
+    #[error(
+        "\"{0}\" isn't a valid attitude. Please use Lawful, Neutral, or Chaotic (or L, N, or C)."
+    )]
+    Attitude(String),
+    /// Not a valid morality
+    #[error("\"{0}\" isn't a valid morality. Please use Good, Neutral, or Evil (or G, N, or E).")]
+    Morality(String),
+    /// Not a valid alignment
+    #[error(
+        "\"{0}\" isn't a valid alignment. Please use an alignment such as Chaotic Good or CG."
+    )]
+    Alignment(String),
+    /// Not a valid alignment option
+    #[error("\"{0}\" isn't a valid alignment option. Please use Unaligned, an alignment such as CG, or a range such as \"any chaotic\" or \"any non-good\".")]
+    AlignmentOption(String),
+}
+
 /// Trait to describe how this entity affects a character's alignment
 pub trait AlignmentInfluences {
     /// List of attitude influences
@@ -0,0 +1,220 @@
+use std::{fmt, str::FromStr};
+
+use rand::Rng;
+use rand_utils::SliceExpRandom;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{Alignment, Attitude, Morality, ParseAlignmentError};
+
+/// Alignments a creature can have, as listed in a stat block. Covers creatures
+/// without an alignment, a single alignment, or a range of alignments such as
+/// "any non-good alignment".
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum AlignmentOption {
+    /// Creatures that lack the capacity for rational thought, and don't have
+    /// an alignment
+    Unaligned,
+    /// Any of the nine alignments
+    #[default]
+    Any,
+    /// A single alignment
+    Exactly(Alignment),
+    /// Any alignment with this attitude, such as "any chaotic alignment"
+    AnyAttitude(Attitude),
+    /// Any alignment with this morality, such as "any evil alignment"
+    AnyMorality(Morality),
+    /// Any alignment without this attitude, such as "any non-lawful alignment"
+    AnyExceptAttitude(Attitude),
+    /// Any alignment without this morality, such as "any non-good alignment"
+    AnyExceptMorality(Morality),
+}
+
+impl AlignmentOption {
+    /// Whether an alignment is allowed by this option
+    ///
+    /// ```
+    /// use alignments::{Alignment, AlignmentOption, Morality};
+    ///
+    /// let option = AlignmentOption::AnyExceptMorality(Morality::Good);
+    /// assert!(option.allows(Alignment::LAWFUL_EVIL));
+    /// assert!(!option.allows(Alignment::CHAOTIC_GOOD));
+    /// ```
+    #[must_use]
+    pub fn allows(self, alignment: Alignment) -> bool {
+        match self {
+            Self::Unaligned => false,
+            Self::Any => true,
+            Self::Exactly(a) => a == alignment,
+            Self::AnyAttitude(attitude) => alignment.attitude == attitude,
+            Self::AnyMorality(morality) => alignment.morality == morality,
+            Self::AnyExceptAttitude(attitude) => alignment.attitude != attitude,
+            Self::AnyExceptMorality(morality) => alignment.morality != morality,
+        }
+    }
+
+    /// Every alignment allowed by this option. Empty if unaligned.
+    #[must_use]
+    pub fn alignments(self) -> Vec<Alignment> {
+        Attitude::iter()
+            .flat_map(|attitude| Morality::iter().map(move |m| Alignment::new(attitude, m)))
+            .filter(|&a| self.allows(a))
+            .collect()
+    }
+
+    /// Resolve to a concrete alignment, weighted by influences from other
+    /// choices on the character sheet in the same way as [`Alignment::gen`].
+    /// Returns `None` if unaligned.
+    ///
+    /// ```
+    /// use alignments::{AlignmentOption, Attitude};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let option: AlignmentOption = "any chaotic alignment".parse()?;
+    /// let alignment = option.gen(&mut rng, &[], &[]).unwrap();
+    /// assert!(option.allows(alignment));
+    ///
+    /// assert_eq!(AlignmentOption::Unaligned.gen(&mut rng, &[], &[]), None);
+    /// # Ok::<(), alignments::ParseAlignmentError>(())
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn gen<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+        attitude_influences: &[Attitude],
+        morality_influences: &[Morality],
+    ) -> Option<Alignment> {
+        match self {
+            Self::Unaligned => None,
+            Self::Any => Some(Alignment::gen(
+                rng,
+                attitude_influences,
+                morality_influences,
+            )),
+            Self::Exactly(alignment) => Some(alignment),
+            _ => self
+                .alignments()
+                .choose_exp_weighted(rng, |a| a.weight(attitude_influences, morality_influences))
+                .ok()
+                .copied(),
+        }
+    }
+
+    /// Probability of [`AlignmentOption::gen`] choosing each allowed
+    /// alignment with the given influences. Empty if unaligned.
+    #[must_use]
+    pub fn probabilities(
+        self,
+        attitude_influences: &[Attitude],
+        morality_influences: &[Morality],
+    ) -> Vec<(Alignment, f64)> {
+        let alignments = self.alignments();
+        let probabilities = rand_utils::exp_probabilities(
+            &alignments
+                .iter()
+                .map(|a| a.weight(attitude_influences, morality_influences))
+                .collect::<Vec<_>>(),
+        );
+        alignments.into_iter().zip(probabilities).collect()
+    }
+}
+
+impl From<Alignment> for AlignmentOption {
+    fn from(alignment: Alignment) -> Self {
+        Self::Exactly(alignment)
+    }
+}
+
+impl fmt::Display for AlignmentOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lowercase = |value: &dyn fmt::Display| value.to_string().to_lowercase();
+        match self {
+            Self::Unaligned => write!(f, "Unaligned"),
+            Self::Any => write!(f, "Any alignment"),
+            Self::Exactly(alignment) => write!(f, "{alignment}"),
+            Self::AnyAttitude(attitude) => write!(f, "Any {} alignment", lowercase(attitude)),
+            Self::AnyMorality(morality) => write!(f, "Any {} alignment", lowercase(morality)),
+            Self::AnyExceptAttitude(attitude) => {
+                write!(f, "Any non-{} alignment", lowercase(attitude))
+            }
+            Self::AnyExceptMorality(morality) => {
+                write!(f, "Any non-{} alignment", lowercase(morality))
+            }
+        }
+    }
+}
+
+impl From<AlignmentOption> for String {
+    fn from(option: AlignmentOption) -> Self {
+        option.to_string()
+    }
+}
+
+impl FromStr for AlignmentOption {
+    type Err = ParseAlignmentError;
+
+    /// Parse an alignment option the way it is written in a stat block,
+    /// ignoring case. Neutral is ambiguous, so "any neutral" isn't allowed.
+    ///
+    /// ```
+    /// use alignments::{Alignment, AlignmentOption, Attitude, Morality};
+    ///
+    /// assert_eq!("unaligned".parse(), Ok(AlignmentOption::Unaligned));
+    /// assert_eq!("Any alignment".parse(), Ok(AlignmentOption::Any));
+    /// assert_eq!("CG".parse(), Ok(AlignmentOption::Exactly(Alignment::CHAOTIC_GOOD)));
+    /// assert_eq!("any chaotic".parse(), Ok(AlignmentOption::AnyAttitude(Attitude::Chaotic)));
+    /// assert_eq!(
+    ///     "Any non-good alignment".parse(),
+    ///     Ok(AlignmentOption::AnyExceptMorality(Morality::Good))
+    /// );
+    /// assert!("any neutral".parse::<AlignmentOption>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAlignmentError::AlignmentOption(s.to_string());
+        let lowercase = s.trim().to_ascii_lowercase();
+        let option = lowercase
+            .strip_suffix("alignment")
+            .unwrap_or(&lowercase)
+            .trim();
+
+        if option == "unaligned" {
+            return Ok(Self::Unaligned);
+        }
+        let Some(rest) = option.strip_prefix("any") else {
+            return option.parse().map(Self::Exactly).map_err(|_| invalid());
+        };
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return Ok(Self::Any);
+        }
+        let (except, rest) = match rest.strip_prefix("non") {
+            Some(rest) => (true, rest.trim_start_matches(['-', ' '])),
+            None => (false, rest),
+        };
+
+        match (rest.parse(), rest.parse()) {
+            (Ok(morality @ (Morality::Good | Morality::Evil)), _) if except => {
+                Ok(Self::AnyExceptMorality(morality))
+            }
+            (Ok(morality @ (Morality::Good | Morality::Evil)), _) => {
+                Ok(Self::AnyMorality(morality))
+            }
+            (_, Ok(attitude @ (Attitude::Lawful | Attitude::Chaotic))) if except => {
+                Ok(Self::AnyExceptAttitude(attitude))
+            }
+            (_, Ok(attitude @ (Attitude::Lawful | Attitude::Chaotic))) => {
+                Ok(Self::AnyAttitude(attitude))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl TryFrom<String> for AlignmentOption {
+    type Error = ParseAlignmentError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
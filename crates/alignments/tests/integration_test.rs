@@ -0,0 +1,141 @@
+//! Integration tests for the `alignments` crate
+#![warn(
+    clippy::pedantic,
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    rust_2021_compatibility,
+    unused
+)]
+
+use alignments::{Alignment, AlignmentOption, Attitude, Morality};
+use serde_json::json;
+use strum::IntoEnumIterator;
+
+#[test]
+fn parse_every_alignment() {
+    for attitude in Attitude::iter() {
+        for morality in Morality::iter() {
+            let alignment = Alignment::new(attitude, morality);
+            let abbreviation = format!(
+                "{}{}",
+                &attitude.to_string()[..1],
+                &morality.to_string()[..1]
+            );
+
+            assert_eq!(alignment.to_string().parse(), Ok(alignment));
+            assert_eq!(alignment.to_string().to_lowercase().parse(), Ok(alignment));
+            assert_eq!(abbreviation.parse(), Ok(alignment));
+            assert_eq!(abbreviation.to_lowercase().parse(), Ok(alignment));
+            assert_eq!(format!("{attitude} {morality}").parse(), Ok(alignment));
+        }
+    }
+
+    for invalid in [
+        "",
+        "Good",
+        "GL",
+        "Lawful Lawful",
+        "Chaotic Good Evil",
+        "XX",
+        "True Good",
+        "true evil",
+        "True",
+    ] {
+        assert!(invalid.parse::<Alignment>().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn deserialize_alignments() {
+    let alignment: Alignment = serde_json::from_value(json!("CG")).unwrap();
+    assert_eq!(alignment, Alignment::CHAOTIC_GOOD);
+    assert_eq!(
+        serde_json::from_value::<Alignment>(json!(alignment)).unwrap(),
+        alignment
+    );
+
+    let attitudes: Vec<Attitude> = serde_json::from_value(json!(["L", "Chaotic"])).unwrap();
+    assert_eq!(attitudes, [Attitude::Lawful, Attitude::Chaotic]);
+    let moralities: Vec<Morality> = serde_json::from_value(json!(["n", "Evil"])).unwrap();
+    assert_eq!(moralities, [Morality::Neutral, Morality::Evil]);
+
+    let error = serde_json::from_value::<Alignment>(json!("Lawful Awesome")).unwrap_err();
+    assert!(error.to_string().contains("isn't a valid alignment"));
+}
+
+#[test]
+fn probabilities_match_generation() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let attitudes = [Attitude::Lawful, Attitude::Neutral];
+    let moralities = [Morality::Evil];
+    let samples = 20_000;
+
+    let generated = (0..samples)
+        .map(|_| Alignment::gen(&mut rng, &attitudes, &moralities))
+        .collect::<Vec<_>>();
+
+    for (alignment, probability) in Alignment::probabilities(&attitudes, &moralities) {
+        let count = generated.iter().filter(|&&a| a == alignment).count();
+        let frequency = f64::from(u32::try_from(count).unwrap()) / f64::from(samples);
+        assert!(
+            (frequency - probability).abs() < 0.02,
+            "{alignment}: {frequency} vs {probability}"
+        );
+    }
+}
+
+#[test]
+fn alignment_options() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = [
+        AlignmentOption::Unaligned,
+        AlignmentOption::Any,
+        AlignmentOption::Exactly(Alignment::LAWFUL_NEUTRAL),
+        AlignmentOption::AnyAttitude(Attitude::Lawful),
+        AlignmentOption::AnyMorality(Morality::Evil),
+        AlignmentOption::AnyExceptAttitude(Attitude::Chaotic),
+        AlignmentOption::AnyExceptMorality(Morality::Good),
+    ];
+    let expected_counts = [0, 9, 1, 3, 3, 6, 6];
+
+    for (option, expected) in options.into_iter().zip(expected_counts) {
+        // Round trips through its text and serialized forms
+        assert_eq!(option.to_string().parse(), Ok(option));
+        assert_eq!(
+            serde_json::from_value::<AlignmentOption>(json!(option)).unwrap(),
+            option
+        );
+        assert_eq!(option.alignments().len(), expected);
+
+        // Always resolves to an allowed alignment, even with opposing influences
+        for _ in 0..50 {
+            let alignment = option.gen(&mut rng, &[Attitude::Chaotic], &[Morality::Good]);
+            assert_eq!(alignment.is_some(), expected > 0);
+            assert!(alignment.map_or(true, |a| option.allows(a)));
+        }
+
+        let total = option
+            .probabilities(&[Attitude::Chaotic], &[Morality::Good])
+            .iter()
+            .map(|(_, p)| p)
+            .sum::<f64>();
+        assert!(expected == 0 || (total - 1.0).abs() < 1e-9);
+    }
+
+    // Any alignment is the same as generating an alignment directly
+    for ((a, p), (b, q)) in AlignmentOption::Any
+        .probabilities(&[Attitude::Lawful], &[Morality::Evil, Morality::Neutral])
+        .into_iter()
+        .zip(Alignment::probabilities(
+            &[Attitude::Lawful],
+            &[Morality::Evil, Morality::Neutral],
+        ))
+    {
+        assert_eq!(a, b);
+        assert!((p - q).abs() < 1e-9);
+    }
+}
@@ -16,13 +16,17 @@
 
 use std::borrow::Cow;
 
-use abilities::AbilityScores;
+use abilities::{
+    Ability, AbilityScores, AbilityScoresError, CombatRole, GenerationMethod, PointBuyError,
+    RacialIncreaseRule, Skill, MAX_LEVEL,
+};
 use alignments::{Alignment, AlignmentInfluences};
-use deities::{Deities, Deity, Pantheon};
+use deities::{Deities, Deity, DeityError, DeityOptions, Pantheon, Patron};
 use descriptions::{Appearance, Backstory};
 use races::{Race, RaceGenerator};
 use rand::{distributions::Standard, prelude::Distribution, Rng};
-use serde::Serialize;
+use rand_utils::{GeneratorVersion, SliceExpRandom, UNLIKELY_PENALTY};
+use serde::{Deserialize, Serialize};
 use sizes::{HeightAndWeight, Size};
 use sources::Sources;
 use thiserror::Error;
@@ -41,8 +45,13 @@ pub struct Character {
     pub deity: Option<Deity>,
     /// The character's height and weight
     pub height_and_weight: Option<HeightAndWeight>,
+    /// The character's level
+    pub level: Option<u8>,
     /// The character's name
     pub name: String,
+    /// Patron or philosophy the character serves instead of a deity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patron: Option<Patron>,
     /// Race of the character
     pub race: Option<Race>,
 }
@@ -71,8 +80,74 @@ impl Character {
     /// ```
     #[must_use]
     #[tracing::instrument(skip(rng))]
-    pub fn gen_ability_scores<R: Rng + ?Sized>(mut self, rng: &mut R) -> Self {
-        self.ability_scores = Some(rng.gen::<AbilityScores>());
+    pub fn gen_ability_scores<R: Rng + ?Sized>(self, rng: &mut R) -> Self {
+        self.gen_ability_scores_with_method(rng, GenerationMethod::default())
+    }
+
+    /// Generate and add base ability scores for your character, using a
+    /// specific method.
+    ///
+    /// ```
+    /// use abilities::GenerationMethod;
+    /// use characters::Character;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new()
+    ///     .gen_ability_scores_with_method(&mut rng, GenerationMethod::PointBuy);
+    /// ```
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_ability_scores_with_method<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+        method: GenerationMethod,
+    ) -> Self {
+        self.ability_scores = Some(AbilityScores::gen_with_method(rng, method));
+        self
+    }
+
+    /// Generate and add base ability scores for your character, using a
+    /// specific method and assigning the highest scores for a combat role.
+    ///
+    /// ```
+    /// use abilities::{CombatRole, GenerationMethod};
+    /// use characters::Character;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new().gen_ability_scores_with_role(
+    ///     &mut rng,
+    ///     GenerationMethod::Roll,
+    ///     CombatRole::Spellslinger,
+    /// );
+    /// ```
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_ability_scores_with_role<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+        method: GenerationMethod,
+        role: CombatRole,
+    ) -> Self {
+        self.ability_scores = Some(AbilityScores::gen_with_role(rng, method, role));
+        self
+    }
+
+    /// Use existing ability scores for your character, rather than
+    /// generating them.
+    ///
+    /// ```
+    /// use abilities::{Ability, AbilityScores};
+    /// use characters::Character;
+    ///
+    /// let scores: AbilityScores = serde_json::from_str(
+    ///     r#"{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}"#,
+    /// )?;
+    /// let character = Character::new().with_ability_scores(scores);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_ability_scores(mut self, ability_scores: AbilityScores) -> Self {
+        self.ability_scores = Some(ability_scores);
         self
     }
 
@@ -96,15 +171,103 @@ impl Character {
     ///
     /// Will error if ability scores are not already chosen.
     #[tracing::instrument(skip(rng))]
-    pub fn gen_race<R: Rng + ?Sized>(mut self, rng: &mut R) -> Result<Self, CharacterBuildError> {
+    pub fn gen_race<R: Rng + ?Sized>(self, rng: &mut R) -> Result<Self, CharacterBuildError> {
+        self.gen_race_with_rule(rng, RacialIncreaseRule::default())
+    }
+
+    /// Generate a race for your character, choosing which rules to use for
+    /// the race's ability score increases.
+    ///
+    /// ```
+    /// use abilities::RacialIncreaseRule;
+    /// use characters::Character;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new()
+    ///     .gen_ability_scores(&mut rng)
+    ///     .gen_race_with_rule(&mut rng, RacialIncreaseRule::Fixed)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Will error if ability scores are not already chosen, or if fixed
+    /// increases would take a score over 20.
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_race_with_rule<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+        rule: RacialIncreaseRule,
+    ) -> Result<Self, CharacterBuildError> {
         let race = rng.gen::<Race>();
         // Generate ability score increases
-        self.try_ability_scores()?
-            .gen_racial_increases(rng, race.ability_increases());
+        let ability_scores = self.try_ability_scores()?;
+        match rule {
+            RacialIncreaseRule::Fixed if !race.fixed_ability_increases().is_empty() => {
+                ability_scores.add_racial_increases(race.fixed_ability_increases())?;
+            }
+            RacialIncreaseRule::Fixed | RacialIncreaseRule::Floating => {
+                ability_scores.gen_racial_increases(rng, race.ability_increases());
+            }
+        }
         self.race = Some(race);
         Ok(self)
     }
 
+    /// Make the character proficient in the skills and saving throws given in
+    /// the options.
+    fn with_proficiencies(mut self, options: &CharacterOptions) -> Self {
+        if let Some(scores) = self.ability_scores.as_mut() {
+            scores.set_proficiencies(
+                &options.skill_proficiencies,
+                &options.skill_expertise,
+                &options.saving_throw_proficiencies,
+            );
+        }
+        self
+    }
+
+    /// Raise your character to a given level, choosing Ability Score
+    /// Improvements for any levels gained along the way. Characters without
+    /// a level are treated as 1st level.
+    ///
+    /// Should be done after the race is chosen, so that racial increases are
+    /// taken into account.
+    ///
+    /// ```
+    /// use characters::Character;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new()
+    ///     .gen_ability_scores(&mut rng)
+    ///     .gen_race(&mut rng)?
+    ///     .gen_level(&mut rng, 8)?;
+    ///
+    /// assert_eq!(character.level, Some(8));
+    /// assert_eq!(character.ability_scores.unwrap().proficiency_bonus(), 3);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Will error if ability scores are not already chosen, or if the level
+    /// is above 20 or below the character's current level.
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_level<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+        level: u8,
+    ) -> Result<Self, CharacterBuildError> {
+        let current = self.level.unwrap_or(1);
+        if !(current..=MAX_LEVEL).contains(&level) {
+            return Err(CharacterBuildError::InvalidLevel { level, current });
+        }
+
+        self.try_ability_scores()?
+            .gen_improvements(rng, current + 1..=level)
+            .set_proficiency_bonus(abilities::proficiency_bonus(level));
+        self.level = Some(level);
+        Ok(self)
+    }
+
     /// Helper function to return a reference to the race, otherwise error
     fn try_race(&self) -> Result<&Race, CharacterBuildError> {
         self.race.as_ref().ok_or(CharacterBuildError::MissingRace)
@@ -222,15 +385,137 @@ impl Character {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[tracing::instrument(skip(rng))]
-    pub fn gen_deity<R: Rng + ?Sized>(mut self, rng: &mut R) -> Result<Self, CharacterBuildError> {
+    pub fn gen_deity<R: Rng + ?Sized>(self, rng: &mut R) -> Result<Self, CharacterBuildError> {
+        self.gen_deity_with_followers(rng, &[])
+    }
+
+    /// Generate an deity for your character, making deities who are rivals of
+    /// the pantheons of the character's race less likely.
+    ///
+    /// ```
+    /// use characters::Character;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new()
+    ///     .gen_ability_scores(&mut rng)
+    ///     .gen_race(&mut rng)?
+    ///     .gen_deity_avoiding_rivals(&mut rng)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_deity_avoiding_rivals<R: Rng + ?Sized>(
+        self,
+        rng: &mut R,
+    ) -> Result<Self, CharacterBuildError> {
+        let follower_pantheons = self.try_race()?.pantheons().into_owned();
+        self.gen_deity_with_followers(rng, &follower_pantheons)
+    }
+
+    /// Helper for generating a deity, making deities who are rivals of the
+    /// follower pantheons less likely
+    ///
+    /// # Errors
+    ///
+    /// Will error if race isn't already chosen
+    fn gen_deity_with_followers<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+        follower_pantheons: &[Pantheon],
+    ) -> Result<Self, CharacterBuildError> {
         self.deity = Deity::gen(
             rng,
-            None,
-            &self.pantheons()?,
-            &self.attitude(),
-            &self.morality(),
-            self.deity_required()?,
-        );
+            &DeityOptions {
+                likely_pantheons: &self.pantheons()?,
+                follower_pantheons,
+                attitude_influences: &self.attitude(),
+                morality_influences: &self.morality(),
+                required: self.deity_required()?,
+                ..DeityOptions::default()
+            },
+        )?;
+        Ok(self)
+    }
+
+    /// Generate what your character is devoted to: a deity, a patron or
+    /// philosophy, or nothing at all. Races that require a deity always
+    /// choose one. Races drawn to certain kinds of patrons, such as
+    /// dragonborn and philosophies, serve those kinds of patrons as often as
+    /// deities, while other races are much less likely to serve a patron. If
+    /// an alignment is already chosen, deities and patrons who share it are
+    /// more likely.
+    ///
+    /// ```
+    /// use characters::Character;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let character = Character::new()
+    ///     .gen_ability_scores(&mut rng)
+    ///     .gen_race(&mut rng)?
+    ///     .gen_devotion(&mut rng)?;
+    ///
+    /// assert!(character.deity.is_none() || character.patron.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if race isn't already chosen
+    ///
+    /// # Panics
+    ///
+    /// Will panic if weighting logic is wrong
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_devotion<R: Rng + ?Sized>(
+        mut self,
+        rng: &mut R,
+    ) -> Result<Self, CharacterBuildError> {
+        let attitude = self
+            .alignment
+            .as_ref()
+            .map(AlignmentInfluences::attitude)
+            .unwrap_or_default()
+            .into_owned();
+        let morality = self
+            .alignment
+            .as_ref()
+            .map(AlignmentInfluences::morality)
+            .unwrap_or_default()
+            .into_owned();
+        let race = self.try_race()?;
+        let patron_kinds = race.patron_kinds().into_owned();
+        let follower_pantheons = race.pantheons().into_owned();
+
+        let deity = Deity::gen(
+            rng,
+            &DeityOptions {
+                likely_pantheons: &self.pantheons()?,
+                follower_pantheons: &follower_pantheons,
+                attitude_influences: &attitude,
+                morality_influences: &morality,
+                required: true,
+                ..DeityOptions::default()
+            },
+        )?;
+        let mut choices = vec![(
+            (deity, None),
+            deity.map_or(0, |d| d.alignment.weight(&attitude, &morality)),
+        )];
+        if !self.deity_required()? {
+            let patron = Patron::gen(rng, &patron_kinds, &attitude, &morality);
+            let penalty = if patron_kinds.is_empty() {
+                UNLIKELY_PENALTY
+            } else {
+                0
+            };
+            choices.push((
+                (None, Some(patron)),
+                patron.alignment.weight(&attitude, &morality) - penalty,
+            ));
+            choices.push(((None, None), 0));
+        }
+
+        (self.deity, self.patron) = choices.choose_exp_weighted(rng, |c| c.1).unwrap().0;
         Ok(self)
     }
 
@@ -250,17 +535,156 @@ impl Character {
         self
     }
 
+    /// Generate a fully random character using a specific version of the
+    /// generator. Paired with a seeded rng, the same version will always
+    /// generate the same character.
+    ///
+    /// ```
+    /// use characters::Character;
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let version = GeneratorVersion::V1;
+    /// let character = Character::gen_with_version(&mut version.rng_from_seed(1), version);
+    /// let again = Character::gen_with_version(&mut version.rng_from_seed(1), version);
+    ///
+    /// assert_eq!(character.name, again.name);
+    /// ```
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_with_version<R: Rng + ?Sized>(rng: &mut R, version: GeneratorVersion) -> Self {
+        Self::gen_with_options(rng, version, &CharacterOptions::default())
+    }
+
+    /// Generate a fully random character using a specific version of the
+    /// generator, with options for how each part is generated.
+    ///
+    /// ```
+    /// use abilities::GenerationMethod;
+    /// use characters::{Character, CharacterOptions};
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let options = CharacterOptions {
+    ///     ability_method: Some(GenerationMethod::PointBuy),
+    ///     ..CharacterOptions::default()
+    /// };
+    /// let character = Character::gen_with_options(&mut rng, GeneratorVersion::LATEST, &options);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the options are invalid, such as a level above 20. Use
+    /// [`Character::try_gen_with_options`] for options provided by users.
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen_with_options<R: Rng + ?Sized>(
+        rng: &mut R,
+        version: GeneratorVersion,
+        options: &CharacterOptions,
+    ) -> Self {
+        // Not a result because we should be calling these steps in the right order
+        Self::gen(rng, version, options).unwrap()
+    }
+
+    /// Generate a fully random character using a specific version of the
+    /// generator, with options for how each part is generated.
+    ///
+    /// ```
+    /// use characters::{Character, CharacterOptions};
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let options = CharacterOptions {
+    ///     level: Some(21),
+    ///     ..CharacterOptions::default()
+    /// };
+    /// assert!(Character::try_gen_with_options(&mut rng, GeneratorVersion::LATEST, &options).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if the options are invalid, such as a level above 20, or
+    /// ability scores that aren't a legal point buy when using point buy.
+    /// Also errors if ability scores are provided along with options for
+    /// generating them, other than point buy.
+    #[tracing::instrument(skip(rng))]
+    pub fn try_gen_with_options<R: Rng + ?Sized>(
+        rng: &mut R,
+        version: GeneratorVersion,
+        options: &CharacterOptions,
+    ) -> Result<Self, CharacterBuildError> {
+        Self::gen(rng, version, options)
+    }
+
     /// Helper method to generate a full character in the right order with a result.
+    ///
+    /// The order of these steps affects the output for a given seed, so any
+    /// change to it needs a new [`GeneratorVersion`].
     #[tracing::instrument(skip(rng))]
-    fn gen<R: Rng + ?Sized>(rng: &mut R) -> Result<Self, CharacterBuildError> {
-        Ok(Character::new()
-            .gen_ability_scores(rng)
-            .gen_race(rng)?
-            .gen_name(rng)?
-            .gen_age(rng)?
-            .gen_height_and_weight(rng)?
-            .gen_deity(rng)?
-            .gen_alignment(rng))
+    fn gen<R: Rng + ?Sized>(
+        rng: &mut R,
+        version: GeneratorVersion,
+        options: &CharacterOptions,
+    ) -> Result<Self, CharacterBuildError> {
+        match version {
+            GeneratorVersion::V1
+            | GeneratorVersion::V2
+            | GeneratorVersion::V3
+            | GeneratorVersion::V4 => {
+                let method = options.ability_method.unwrap_or_default();
+                let character = match (&options.ability_scores, options.combat_role) {
+                    (Some(_), Some(_)) => {
+                        return Err(CharacterBuildError::ConflictingAbilityOption("combat_role"))
+                    }
+                    (Some(scores), None) => {
+                        match options.ability_method {
+                            Some(GenerationMethod::PointBuy) => {
+                                scores.validate_point_buy()?;
+                            }
+                            Some(_) => {
+                                return Err(CharacterBuildError::ConflictingAbilityOption(
+                                    "ability_method",
+                                ))
+                            }
+                            None => {}
+                        }
+                        Character::new().with_ability_scores(scores.clone())
+                    }
+                    (None, Some(role)) => {
+                        Character::new().gen_ability_scores_with_role(rng, method, role)
+                    }
+                    (None, None) => Character::new().gen_ability_scores_with_method(rng, method),
+                };
+
+                let character = character
+                    .gen_race_with_rule(rng, options.racial_increases)?
+                    .gen_level(rng, options.level.unwrap_or(1))?
+                    .with_proficiencies(options)
+                    .gen_name(rng)?
+                    .gen_age(rng)?
+                    .gen_height_and_weight(rng)?;
+                let mut character = match version {
+                    GeneratorVersion::V1 | GeneratorVersion::V2 => {
+                        character.gen_deity(rng)?.gen_alignment(rng)
+                    }
+                    // Rivalries between pantheons are only avoided from V3 onward
+                    GeneratorVersion::V3 => {
+                        character.gen_deity_avoiding_rivals(rng)?.gen_alignment(rng)
+                    }
+                    // Patrons are only chosen from V4 onward, after the
+                    // alignment so that it can influence them
+                    GeneratorVersion::V4 => character.gen_alignment(rng).gen_devotion(rng)?,
+                };
+                character.ability_scores = character.ability_scores.map(|s| s.for_version(version));
+                character.deity = character.deity.map(|d| d.for_version(version));
+                // Levels were only reported from V2 onward
+                if version == GeneratorVersion::V1 {
+                    character.level = None;
+                }
+
+                Ok(character)
+            }
+        }
     }
 }
 
@@ -269,6 +693,11 @@ impl AlignmentInfluences for Character {
         self.deity
             .as_ref()
             .map(alignments::AlignmentInfluences::attitude)
+            .or_else(|| {
+                self.patron
+                    .as_ref()
+                    .map(alignments::AlignmentInfluences::attitude)
+            })
             .unwrap_or_default()
     }
 
@@ -276,6 +705,11 @@ impl AlignmentInfluences for Character {
         self.deity
             .as_ref()
             .map(alignments::AlignmentInfluences::morality)
+            .or_else(|| {
+                self.patron
+                    .as_ref()
+                    .map(alignments::AlignmentInfluences::morality)
+            })
             .unwrap_or_default()
     }
 }
@@ -302,11 +736,44 @@ impl Distribution<Character> for Standard {
     /// Generate a fully random character.
     #[tracing::instrument(skip(rng))]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Character {
-        // Not a result because we should be calling these steps in the right order
-        Character::gen(rng).unwrap()
+        Character::gen_with_version(rng, GeneratorVersion::LATEST)
     }
 }
 
+/// Options for how a full character is generated
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CharacterOptions {
+    /// Method used to generate the base ability scores. Rolled if not
+    /// provided.
+    pub ability_method: Option<GenerationMethod>,
+    /// Base ability scores to use instead of generating them. Racial
+    /// increases are still applied on top of these. Can't be combined with a
+    /// combat role or an ability method, except for point buy, which checks
+    /// that the scores are a legal point buy.
+    ///
+    /// Skipped when deserializing, since a map of scores can't be expressed in
+    /// flat options such as a query string.
+    #[serde(skip)]
+    pub ability_scores: Option<AbilityScores>,
+    /// Combat role to assign the highest generated scores for. Random if
+    /// not provided.
+    pub combat_role: Option<CombatRole>,
+    /// Level of the character. 1st level if not provided.
+    pub level: Option<u8>,
+    /// Rules for which abilities the race's ability score increases apply to
+    pub racial_increases: RacialIncreaseRule,
+    /// Skills the character is proficient in
+    #[serde(rename = "skill")]
+    pub skill_proficiencies: Vec<Skill>,
+    /// Skills the character has expertise in
+    #[serde(rename = "expertise")]
+    pub skill_expertise: Vec<Skill>,
+    /// Saving throws the character is proficient in
+    #[serde(rename = "saving_throw")]
+    pub saving_throw_proficiencies: Vec<Ability>,
+}
+
 /// Errors caused by an invalid character build
 #[derive(Debug, Eq, Error, PartialEq)]
 pub enum CharacterBuildError {
@@ -316,6 +783,27 @@ pub enum CharacterBuildError {
     /// Error produced when a race is required to make a decision
     #[error("This character is missing a race. Please choose or generate a race option before this step.")]
     MissingRace,
+    /// Error produced when a character can't reach a level
+    #[error("A level {current} character can't become level {level}. Please choose a level between {current} and 20.")]
+    InvalidLevel {
+        /// Level that was requested
+        level: u8,
+        /// Current level of the character
+        current: u8,
+    },
+    /// Error produced when a deity can't be chosen
+    #[error(transparent)]
+    Deity(#[from] DeityError),
+    /// Error produced when ability scores are provided along with an option
+    /// for generating them
+    #[error("Ability scores were provided, so they can't also be generated using {0}. Please provide either ability scores or {0}, not both.")]
+    ConflictingAbilityOption(&'static str),
+    /// Error produced when ability scores can't be increased
+    #[error(transparent)]
+    AbilityScores(#[from] AbilityScoresError),
+    /// Error produced when provided ability scores aren't a legal point buy
+    #[error(transparent)]
+    PointBuy(#[from] PointBuyError),
 }
 
 /// Serializable, public interface for a character
@@ -332,8 +820,14 @@ struct CharacterSheet {
     /// The character's height and weight
     #[serde(flatten)]
     pub height_and_weight: Option<HeightAndWeight>,
+    /// The character's level
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<u8>,
     /// Name of the character
     pub name: String,
+    /// Patron or philosophy the character serves instead of a deity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patron: Option<Patron>,
     /// Chosen race of the character
     pub race: Option<String>,
     /// The character's size
@@ -348,7 +842,9 @@ impl From<Character> for CharacterSheet {
             alignment: character.alignment,
             deity: character.deity,
             height_and_weight: character.height_and_weight,
+            level: character.level,
             name: character.name,
+            patron: character.patron,
             race: character.race.as_ref().map(Sources::citation),
             size: character.race.as_ref().map(RaceGenerator::size),
         }
@@ -1,3 +1,4 @@
+//! Integration tests for the `characters` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -12,11 +13,15 @@
 
 use std::collections::HashSet;
 
-use abilities::Ability;
-use characters::{Character, CharacterBuildError};
+use abilities::{
+    Ability, AbilityScoresError, CombatRole, GenerationMethod, PointBuyError, RacialIncreaseRule,
+};
+use characters::{Character, CharacterBuildError, CharacterOptions};
+use deities::PatronKind;
 use descriptions::{Appearance, Backstory};
 use races::RaceGenerator;
 use rand::Rng;
+use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
 use serde_json::json;
 use sizes::HeightAndWeight;
 use sources::Sources;
@@ -98,6 +103,82 @@ fn generate_deity() {
     assert!(character.is_ok());
 }
 
+#[test]
+fn generate_devotion() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let characters = (0..100)
+        .map(|_| {
+            Character::new()
+                .gen_ability_scores(&mut rng)
+                .gen_race(&mut rng)
+                .unwrap()
+                .gen_devotion(&mut rng)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(characters
+        .iter()
+        .all(|c| c.deity.is_none() || c.patron.is_none()));
+    assert!(characters.iter().any(|c| c.deity.is_some()));
+    assert!(characters.iter().any(|c| c.patron.is_some()));
+}
+
+#[test]
+fn race_patron_kinds_are_favored() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let patrons = (0..500)
+        .filter_map(|_| {
+            Character::new()
+                .gen_ability_scores(&mut rng)
+                .gen_race(&mut rng)
+                .unwrap()
+                .gen_devotion(&mut rng)
+                .unwrap()
+                .patron
+        })
+        .collect::<Vec<_>>();
+    let philosophies = patrons
+        .iter()
+        .filter(|p| p.kind == PatronKind::Philosophy)
+        .count();
+
+    // Dragonborn are drawn to philosophies
+    assert!(philosophies * 4 > patrons.len() * 3);
+}
+
+#[test]
+fn devotion_is_influenced_by_alignment() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let characters = (0..300)
+        .map(|_| Character::gen_with_version(&mut rng, GeneratorVersion::V4))
+        .collect::<Vec<_>>();
+    let devoted = characters
+        .iter()
+        .filter_map(|c| {
+            let alignment = c
+                .deity
+                .map(|d| d.alignment)
+                .or(c.patron.map(|p| p.alignment))?;
+            Some(Some(alignment) == c.alignment)
+        })
+        .collect::<Vec<_>>();
+    let shared = devoted.iter().filter(|&&shared| shared).count();
+
+    // Only one in nine would share an alignment by chance
+    assert!(shared * 3 > devoted.len());
+}
+
+#[test]
+fn devotion_requires_race() {
+    assert_eq!(
+        Character::new()
+            .gen_devotion(&mut rand_utils::rng_from_entropy())
+            .unwrap_err(),
+        CharacterBuildError::MissingRace
+    );
+}
+
 #[test]
 fn generate_alignment() {
     let mut rng = rand_utils::rng_from_entropy();
@@ -214,3 +295,250 @@ fn race_is_chosen_before_deity() {
         CharacterBuildError::MissingRace
     );
 }
+
+#[test]
+fn seeded_characters_are_stable() {
+    assert_golden_versions(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"),
+        &[
+            GeneratorVersion::V1,
+            GeneratorVersion::V2,
+            GeneratorVersion::V3,
+            GeneratorVersion::V4,
+        ],
+        |version| {
+            (0..10)
+                .map(|seed| Character::gen_with_version(&mut version.rng_from_seed(seed), version))
+                .collect::<Vec<_>>()
+        },
+    );
+}
+
+#[test]
+fn user_supplied_ability_scores() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = CharacterOptions {
+        ability_scores: Some(
+            serde_json::from_str(
+                r#"{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}"#,
+            )
+            .unwrap(),
+        ),
+        ..CharacterOptions::default()
+    };
+
+    let character = Character::gen_with_options(&mut rng, GeneratorVersion::LATEST, &options);
+    let race = character.race.as_ref().unwrap();
+    let serialized = serde_json::to_value(&character).unwrap();
+    let scores = &serialized["ability_scores"];
+
+    // Base scores are kept, and racial increases are applied on top of them
+    for (ability, base) in [
+        ("STR", 15),
+        ("DEX", 14),
+        ("CON", 13),
+        ("INT", 12),
+        ("WIS", 10),
+        ("CHA", 8),
+    ] {
+        assert_eq!(scores[ability]["base"], base);
+    }
+    let mut increases = Ability::iter()
+        .map(|a| scores[a.to_string()]["racial_increase"].as_u64().unwrap())
+        .filter(|&i| i > 0)
+        .collect::<Vec<_>>();
+    increases.sort_unstable();
+    let mut expected = race
+        .ability_increases()
+        .iter()
+        .map(|&i| u64::from(i))
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+    assert_eq!(increases, expected);
+}
+
+#[test]
+fn requested_combat_role() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = CharacterOptions {
+        combat_role: Some(CombatRole::Spellslinger),
+        ..CharacterOptions::default()
+    };
+
+    let character = Character::gen_with_options(&mut rng, GeneratorVersion::LATEST, &options);
+    assert_eq!(
+        character.ability_scores.as_ref().unwrap().combat_role(),
+        Some(CombatRole::Spellslinger)
+    );
+    assert_eq!(
+        serde_json::to_value(&character).unwrap()["ability_scores"]["combat_role"],
+        "spellslinger"
+    );
+}
+
+#[test]
+fn character_levels() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = CharacterOptions {
+        level: Some(12),
+        ..CharacterOptions::default()
+    };
+
+    let character = Character::gen_with_options(&mut rng, GeneratorVersion::LATEST, &options);
+    assert_eq!(character.level, Some(12));
+    let scores = character.ability_scores.as_ref().unwrap();
+    assert_eq!(scores.proficiency_bonus(), 4);
+    assert!(Ability::iter().map(|a| scores.improvement(a)).sum::<u8>() <= 6);
+    assert!(Ability::iter().all(|a| scores.score(a) <= 20));
+    assert_eq!(serde_json::to_value(&character).unwrap()["level"], 12);
+
+    // Levelling up further only adds the new improvements
+    let before = Ability::iter().map(|a| scores.improvement(a)).sum::<u8>();
+    let character = character.gen_level(&mut rng, 16).unwrap();
+    let scores = character.ability_scores.as_ref().unwrap();
+    assert!(Ability::iter().map(|a| scores.improvement(a)).sum::<u8>() <= before + 2);
+    assert_eq!(scores.proficiency_bonus(), 5);
+
+    assert_eq!(
+        character.gen_level(&mut rng, 10).unwrap_err(),
+        CharacterBuildError::InvalidLevel {
+            level: 10,
+            current: 16
+        }
+    );
+    assert!(Character::try_gen_with_options(
+        &mut rng,
+        GeneratorVersion::LATEST,
+        &CharacterOptions {
+            level: Some(21),
+            ..CharacterOptions::default()
+        }
+    )
+    .is_err());
+}
+
+#[test]
+fn fixed_racial_increases() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = CharacterOptions {
+        ability_scores: Some(
+            serde_json::from_str(
+                r#"{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}"#,
+            )
+            .unwrap(),
+        ),
+        racial_increases: RacialIncreaseRule::Fixed,
+        ..CharacterOptions::default()
+    };
+
+    for _ in 0..10 {
+        let character = Character::gen_with_options(&mut rng, GeneratorVersion::LATEST, &options);
+        let race = character.race.as_ref().unwrap();
+        let scores = character.ability_scores.as_ref().unwrap();
+        let serialized = serde_json::to_value(scores).unwrap();
+
+        for ability in Ability::iter() {
+            let expected = race
+                .fixed_ability_increases()
+                .iter()
+                .find(|&&(a, _)| a == ability)
+                .map_or(0, |&(_, increase)| increase);
+            assert_eq!(serialized[ability.to_string()]["racial_increase"], expected);
+        }
+    }
+}
+
+#[test]
+fn user_supplied_point_buy_scores_are_validated() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = |scores: &str| CharacterOptions {
+        ability_method: Some(GenerationMethod::PointBuy),
+        ability_scores: Some(serde_json::from_str(scores).unwrap()),
+        ..CharacterOptions::default()
+    };
+
+    assert!(Character::try_gen_with_options(
+        &mut rng,
+        GeneratorVersion::LATEST,
+        &options(r#"{"STR": 15, "DEX": 15, "CON": 15, "INT": 8, "WIS": 8, "CHA": 8}"#),
+    )
+    .is_ok());
+    assert_eq!(
+        Character::try_gen_with_options(
+            &mut rng,
+            GeneratorVersion::LATEST,
+            &options(r#"{"STR": 15, "DEX": 15, "CON": 15, "INT": 15, "WIS": 8, "CHA": 8}"#),
+        )
+        .unwrap_err(),
+        CharacterBuildError::PointBuy(PointBuyError::Overspent(36))
+    );
+    assert_eq!(
+        Character::try_gen_with_options(
+            &mut rng,
+            GeneratorVersion::LATEST,
+            &options(r#"{"STR": 18, "DEX": 8, "CON": 8, "INT": 8, "WIS": 8, "CHA": 8}"#),
+        )
+        .unwrap_err(),
+        CharacterBuildError::PointBuy(PointBuyError::ScoreOutOfRange(18))
+    );
+}
+
+#[test]
+fn user_supplied_ability_scores_conflict_with_generation_options() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let scores = serde_json::from_str::<abilities::AbilityScores>(
+        r#"{"STR": 15, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}"#,
+    )
+    .unwrap();
+
+    for (options, option) in [
+        (
+            CharacterOptions {
+                combat_role: Some(CombatRole::FrontLine),
+                ..CharacterOptions::default()
+            },
+            "combat_role",
+        ),
+        (
+            CharacterOptions {
+                ability_method: Some(GenerationMethod::StandardArray),
+                ..CharacterOptions::default()
+            },
+            "ability_method",
+        ),
+    ] {
+        let options = CharacterOptions {
+            ability_scores: Some(scores.clone()),
+            ..options
+        };
+        assert_eq!(
+            Character::try_gen_with_options(&mut rng, GeneratorVersion::LATEST, &options)
+                .unwrap_err(),
+            CharacterBuildError::ConflictingAbilityOption(option)
+        );
+    }
+}
+
+#[test]
+fn fixed_racial_increases_over_the_cap_are_rejected() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let options = CharacterOptions {
+        ability_scores: Some(
+            serde_json::from_str(
+                r#"{"STR": 19, "DEX": 14, "CON": 13, "INT": 12, "WIS": 10, "CHA": 8}"#,
+            )
+            .unwrap(),
+        ),
+        racial_increases: RacialIncreaseRule::Fixed,
+        ..CharacterOptions::default()
+    };
+
+    // Only dragonborn are available, with +2 STR
+    assert_eq!(
+        Character::try_gen_with_options(&mut rng, GeneratorVersion::LATEST, &options).unwrap_err(),
+        CharacterBuildError::AbilityScores(AbilityScoresError::IncreaseOverMax {
+            ability: Ability::Strength,
+            score: 21
+        })
+    );
+}
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const BUGBEAR: &[Deity] = &[Deity {
     name: "Hruggek",
@@ -9,4 +9,5 @@ pub const BUGBEAR: &[Deity] = &[Deity {
     pantheon: Pantheon::Bugbear,
     symbols: &["Morningstar"],
     titles: &["god of violence"],
+    lore: Lore::NONE,
 }];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const CELTIC: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Bubbling cauldron", "shield"],
         titles: &["god of weather and crops"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Arawn",
@@ -18,6 +19,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Black star on gray background"],
         titles: &["god of life and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Belenus",
@@ -26,6 +28,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Solar disk and standing stones"],
         titles: &["god of sun, light, and warmth"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Brigantia",
@@ -34,6 +37,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Footbridge"],
         titles: &["goddess of rivers and livestock"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Diancecht",
@@ -42,6 +46,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Crossed oak and mistletoe branches"],
         titles: &["god of medicine and healing"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Dunatis",
@@ -50,6 +55,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Red sun-capped mountain peak"],
         titles: &["god of mountains and peaks"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Goibhniu",
@@ -58,6 +64,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Giant mallet over sword"],
         titles: &["god of smiths and healing"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Lugh",
@@ -66,6 +73,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Pair of long hands"],
         titles: &["god of arts, travel, and commerce"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Manannan mac Lir",
@@ -74,6 +82,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Wave of white water on green"],
         titles: &["god of oceans and sea creatures"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Math Mathonwy",
@@ -82,6 +91,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Staff"],
         titles: &["god of magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Morrigan",
@@ -90,6 +100,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Two crossed spears"],
         titles: &["goddess of battle"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Nuada",
@@ -98,6 +109,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Silver hand on black background"],
         titles: &["god of war and warriors"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Oghma",
@@ -106,6 +118,7 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Unfurled scroll"],
         titles: &["god of speech and writing"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Silvanus",
@@ -114,5 +127,6 @@ pub const CELTIC: &[Deity] = &[
         pantheon: Pantheon::Celtic,
         symbols: &["Summer oak tree"],
         titles: &["god of nature and forests"],
+        lore: Lore::NONE,
     },
 ];
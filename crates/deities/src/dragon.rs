@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const DRAGON: &[Deity] = &[
     Deity {
@@ -10,6 +10,11 @@ pub const DRAGON: &[Deity] = &[
         pantheon: Pantheon::Dragon,
         symbols: &["Dragon's head in profile"],
         titles: &["god of good"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Dragon, "Tiamat")],
+            worshippers: &["Metallic dragons", "Dragonborn", "Paladins"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Tiamat",
@@ -18,5 +23,10 @@ pub const DRAGON: &[Deity] = &[
         pantheon: Pantheon::Dragon,
         symbols: &["Dragon head with five claw marks"],
         titles: &["god of evil"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Dragon, "Bahamut")],
+            worshippers: &["Chromatic dragons", "Dragonborn", "Kobolds"],
+            ..Lore::NONE
+        },
     },
 ];
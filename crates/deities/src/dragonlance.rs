@@ -1,15 +1,16 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const DRAGONLANCE: &[Deity] = &[
     Deity {
         name: "Paladine",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::War],
+        domains: &[Domain::Peace, Domain::War],
         pantheon: Pantheon::Dragonlance,
         symbols: &["Silver triangle"],
         titles: &["god of rulers and guardians"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Branchala",
@@ -18,6 +19,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Bard's harp"],
         titles: &["god of music"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Habbakuk",
@@ -26,6 +28,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Blue bird"],
         titles: &["god of animal life and the sea"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kiri-Jolith",
@@ -34,22 +37,25 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Bison's horns"],
         titles: &["god of honor and war"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Majere",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Knowledge],
+        domains: &[Domain::Knowledge, Domain::Order],
         pantheon: Pantheon::Dragonlance,
         symbols: &["Copper spider"],
         titles: &["god of meditation and order"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Mishakal",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Knowledge, Domain::Life],
+        domains: &[Domain::Knowledge, Domain::Life, Domain::Twilight],
         pantheon: Pantheon::Dragonlance,
         symbols: &["Blue infinity sign"],
         titles: &["goddess of healing"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Solinari",
@@ -58,6 +64,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["White circle or sphere"],
         titles: &["god of good magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gilean",
@@ -66,6 +73,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Open book"],
         titles: &["god of knowledge"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Chislev",
@@ -74,6 +82,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Feather"],
         titles: &["goddess of nature"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Reorx",
@@ -82,6 +91,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Forging hammer"],
         titles: &["god of craft"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Shinare",
@@ -90,6 +100,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Griffon's wing"],
         titles: &["goddess of wealth and trade"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sirrion",
@@ -98,6 +109,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Multi-colored fire"],
         titles: &["god of fire and change"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Zivilyn",
@@ -106,6 +118,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Great green or gold tree"],
         titles: &["god of wisdom"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Lunitari",
@@ -114,6 +127,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Red circle or sphere"],
         titles: &["goddess of neutral magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Takhisis",
@@ -122,6 +136,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Black crescent"],
         titles: &["goddess of night and hatred"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Chemosh",
@@ -130,6 +145,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Yellow skull"],
         titles: &["god of the undead"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hiddukel",
@@ -138,6 +154,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Broken merchant's scales"],
         titles: &["god of lies and greed"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Morgion",
@@ -146,6 +163,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Hood with two red eyes"],
         titles: &["god of disease and secrecy"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sargonnas",
@@ -154,6 +172,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Stylized red condor"],
         titles: &["god of vengeance and fire"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Zeboim",
@@ -162,6 +181,7 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Turtle shell"],
         titles: &["goddess of the sea and storms"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Nuitari",
@@ -170,5 +190,6 @@ pub const DRAGONLANCE: &[Deity] = &[
         pantheon: Pantheon::Dragonlance,
         symbols: &["Black circle or sphere"],
         titles: &["god of evil magic"],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const DROW: &[Deity] = &[
     Deity {
@@ -13,6 +13,16 @@ pub const DROW: &[Deity] = &[
             "goddess of song, beauty, swordwork, hunting, and moonlight",
             "goddess of freedom, moonlight, and song",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Elven, "Corellon Larathian")],
+            rivals: &[DeityRef::new(Pantheon::Drow, "Lolth")],
+            worshippers: &[
+                "Drow who seek to return to the surface",
+                "Hunters",
+                "Dancers",
+            ],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Ghaunadaur",
@@ -21,6 +31,7 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Purple eye with black sclera"],
         titles: &["deity of oozes, slimes, and outcasts"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Keptolo",
@@ -29,6 +40,7 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Mushroom"],
         titles: &["deity of beauty, hedonism, and fertility"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kiaransalee",
@@ -37,6 +49,7 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Female drow hand wearing many silver rings"],
         titles: &["goddess of necromancy", "goddess of the undead"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Malyk",
@@ -45,6 +58,7 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["A flame in a tear or a multihued vortex"],
         titles: &["deity of chaos, rebellion, and wild magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Lolth",
@@ -57,6 +71,16 @@ pub const DROW: &[Deity] = &[
             "the Demon Queen of Spiders",
             "primary god of drow",
         ],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::Elven, "Corellon Larathian"),
+                DeityRef::new(Pantheon::Drow, "Eilistraee"),
+                DeityRef::new(Pantheon::Drow, "Vhaeraun"),
+            ],
+            worshippers: &["Drow", "Driders", "Priestesses of the Underdark"],
+            favored_weapon: Some("Whip"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Selvetarm",
@@ -65,6 +89,7 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Spider over crossed sword and mace"],
         titles: &["god of warriors", "god of warriors and slaughter"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Vhaeraun",
@@ -73,6 +98,12 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Black mask with blue glass lenses inset over eyes"],
         titles: &["god of thieves", "god of arrogance and thieves"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Drow, "Lolth")],
+            worshippers: &["Drow males", "Thieves"],
+            favored_weapon: Some("Shortsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Zinzerena",
@@ -81,5 +112,6 @@ pub const DROW: &[Deity] = &[
         pantheon: Pantheon::Drow,
         symbols: &["Shortsword draped with cloth"],
         titles: &["deity of assassination, illusion, and lies"],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const DUERGAR: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const DUERGAR: &[Deity] = &[
         pantheon: Pantheon::Duergar,
         symbols: &["Mind flayer skill"],
         titles: &["goddess of conquest and psionics"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Laduguer",
@@ -18,5 +19,11 @@ pub const DUERGAR: &[Deity] = &[
         pantheon: Pantheon::Duergar,
         symbols: &["Broken arrow"],
         titles: &["god of magic and slavery", "god of labor and slavery"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Dwarven, "Moradin")],
+            worshippers: &["Duergar", "Slaves"],
+            favored_weapon: Some("Warhammer"),
+            ..Lore::NONE
+        },
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const DWARVEN: &[Deity] = &[
     Deity {
@@ -10,11 +10,12 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Jeweled dagger, point-down"],
         titles: &["god of greed"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Berronar Truesilver",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Life, Domain::Light],
+        domains: &[Domain::Life, Domain::Light, Domain::Peace],
         pantheon: Pantheon::Dwarven,
         symbols: &["Intertwined silver rings"],
         titles: &[
@@ -24,6 +25,11 @@ pub const DWARVEN: &[Deity] = &[
             "the Revered Mother",
             "goddess of hearth, home, and truth",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Dwarven, "Moradin")],
+            worshippers: &["Dwarves", "Families", "Guardians of the home"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Clangeddin Silverbeard",
@@ -32,6 +38,12 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Crossed silver battleaxes"],
         titles: &["god of war and valor", "god of war and strategy"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Dwarven, "Moradin")],
+            worshippers: &["Dwarven warriors"],
+            favored_weapon: Some("Battleaxe"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Dugmaren Brightmantle",
@@ -45,6 +57,7 @@ pub const DWARVEN: &[Deity] = &[
             "the Gleam in the Eye",
             "god of discovery",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Dumathoin",
@@ -61,6 +74,7 @@ pub const DWARVEN: &[Deity] = &[
             "the Keeper of Secrets under the Mountain",
             "the guardian of the dead",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gorm Gulthyn",
@@ -75,6 +89,7 @@ pub const DWARVEN: &[Deity] = &[
             "the protector of dwarves",
             "god of vigilance",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Haela Brightaxe",
@@ -90,6 +105,7 @@ pub const DWARVEN: &[Deity] = &[
             "patron of dwarf fighters",
             "god of combat prowess and luck in battle",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hanseath",
@@ -98,6 +114,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Beer stein"],
         titles: &["deity of festivity, brewing, and song"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Marthammor Duin",
@@ -112,6 +129,7 @@ pub const DWARVEN: &[Deity] = &[
             "deity of lightning and roads",
             "god of explorers, wanderers, and the lost",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Moradin",
@@ -128,6 +146,13 @@ pub const DWARVEN: &[Deity] = &[
             "All-father",
             "Primary deity of dwarves",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Dwarven, "Berronar Truesilver")],
+            rivals: &[DeityRef::new(Pantheon::Duergar, "Laduguer")],
+            worshippers: &["Dwarves", "Smiths", "Miners"],
+            favored_weapon: Some("Warhammer"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Muamman Duathal",
@@ -136,6 +161,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Mace held in gauntlets"],
         titles: &["deity of storms and travel"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Mya",
@@ -144,6 +170,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["A faceless mother figure"],
         titles: &["deity of clan, family, and wisdom"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Roknar",
@@ -152,6 +179,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Hands filled with coins"],
         titles: &["deity of lies and intrigue"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sharindlar",
@@ -164,6 +192,7 @@ pub const DWARVEN: &[Deity] = &[
             "Lady of Life and Mercy",
             "goddess of healing and love",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Thard Harr",
@@ -172,6 +201,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Two clawed guantlets"],
         titles: &["deity of wilderness and hunting"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tharmekh\u{fb}l",
@@ -180,6 +210,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Fiery axe"],
         titles: &["deity of fire, forges, and molten rock"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Thautam",
@@ -188,6 +219,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["Blindfold"],
         titles: &["deity of mysteries, darkness, and lost treasures"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ulaa",
@@ -196,6 +228,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["A miner's pick"],
         titles: &["deity of mining and quarrying"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Valkauna",
@@ -204,6 +237,7 @@ pub const DWARVEN: &[Deity] = &[
         pantheon: Pantheon::Dwarven,
         symbols: &["A silver ewer"],
         titles: &["deity of oaths, birth, aging, and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Vergadain",
@@ -220,5 +254,6 @@ pub const DWARVEN: &[Deity] = &[
             "god of commerce and negotiation",
             "the Merchant King",
         ],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const EBERRON: &[Deity] = &[
     Deity {
@@ -10,14 +10,16 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Sheaf of wheat tied with green ribbon"],
         titles: &["goddess of fertility"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Aureon",
         alignment: Alignment::LAWFUL_NEUTRAL,
-        domains: &[Domain::Knowledge],
+        domains: &[Domain::Knowledge, Domain::Order],
         pantheon: Pantheon::Eberron,
         symbols: &["Open tome"],
         titles: &["god of law and knowledge"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Balinor",
@@ -26,22 +28,25 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Pair of antlers"],
         titles: &["god of beasts and the hunt"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Boldrei",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Life],
+        domains: &[Domain::Life, Domain::Peace, Domain::Twilight],
         pantheon: Pantheon::Eberron,
         symbols: &["Fire in a stone hearth"],
         titles: &["goddess of community and home"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Dol Arrah",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Light, Domain::War],
+        domains: &[Domain::Light, Domain::Twilight, Domain::War],
         pantheon: Pantheon::Eberron,
         symbols: &["Rising sun"],
         titles: &["goddess of sunlight and honor"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Dol Dorn",
@@ -50,6 +55,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Longsword crossed over a shield"],
         titles: &["god of strength at arms"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kol Korran",
@@ -58,6 +64,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Nine-sided gold coin"],
         titles: &["god of trade and wealth"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Olladra",
@@ -66,6 +73,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Domino"],
         titles: &["goddess of good fortune"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Onatar",
@@ -74,6 +82,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Crossed hammer and tongs"],
         titles: &["god of craft"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Devourer",
@@ -82,6 +91,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Bundle of five sharpened bones"],
         titles: &["god of nature's wrath"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Fury",
@@ -90,6 +100,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Winged wyrm with woman's head and upper body"],
         titles: &["goddess of wrath and madness"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Keeper",
@@ -98,6 +109,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Dragonshard stone in the shape of a fang"],
         titles: &["god of greed and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Mockery",
@@ -106,6 +118,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Five blood-spattered tools"],
         titles: &["god of violence and treachery"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Shadow",
@@ -114,6 +127,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Obsidian tower"],
         titles: &["god of dark magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Traveler",
@@ -122,6 +136,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Four crossed, rune-inscribed bones"],
         titles: &["deity of chaos and change"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Silver Flame",
@@ -130,6 +145,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Flame drawn on silver or molded from silver"],
         titles: &["deity of protection and good"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Blood of Vol",
@@ -138,6 +154,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Stylized dragon skull on red teardrop gem"],
         titles: &["philosophy of immortality and undeath"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Cults of the Dragon Below",
@@ -146,6 +163,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Varies"],
         titles: &["deities of madness"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Path of Light",
@@ -154,6 +172,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Brilliant crystal"],
         titles: &["philosophy of light and self-improvement"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Undying Court",
@@ -162,6 +181,7 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Varies"],
         titles: &["elven ancestors"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Spirits of the Past",
@@ -170,5 +190,6 @@ pub const EBERRON: &[Deity] = &[
         pantheon: Pantheon::Eberron,
         symbols: &["Varies"],
         titles: &["elven ancestors"],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const EGYPTIAN: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Solar disk encircled by serpent"],
         titles: &["god of the sun, ruler of the gods"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Anubis",
@@ -18,6 +19,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Black jackal"],
         titles: &["god of judgment and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Apep",
@@ -26,6 +28,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Flaming snake"],
         titles: &["god of evil, fire, and serpents"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Bast",
@@ -34,6 +37,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Cat"],
         titles: &["goddess of cats and vengeance"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Bes",
@@ -42,6 +46,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Image of the misshapen deity"],
         titles: &["god of luck and music"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hathor",
@@ -50,6 +55,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Horned cow's head with lunar disk"],
         titles: &["goddess of love, music, and motherhood"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Imhotep",
@@ -58,6 +64,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Step pyramid"],
         titles: &["god of crafts and medicine"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Isis",
@@ -66,6 +73,12 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Ankh and star"],
         titles: &["goddess of fertility and magic"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Egyptian, "Osiris")],
+            rivals: &[DeityRef::new(Pantheon::Egyptian, "Set")],
+            worshippers: &["Mothers", "Wizards"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Nephthys",
@@ -74,6 +87,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Horns around a lunar disk"],
         titles: &["goddess of death and grief"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Osiris",
@@ -82,6 +96,12 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Crook and flail"],
         titles: &["god of nature and the underworld"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Egyptian, "Isis")],
+            rivals: &[DeityRef::new(Pantheon::Egyptian, "Set")],
+            worshippers: &["Farmers", "The dead"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Ptah",
@@ -90,6 +110,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Bull"],
         titles: &["god of crafts, knowledge, and secrets"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Set",
@@ -98,6 +119,11 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Coiled cobra"],
         titles: &["god of darkness and desert storms"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Egyptian, "Osiris")],
+            worshippers: &["Murderers", "Desert raiders"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Sobek",
@@ -106,6 +132,7 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Crocodile head with horns and plumes"],
         titles: &["god of water and crocodiles"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Thoth",
@@ -114,5 +141,6 @@ pub const EGYPTIAN: &[Deity] = &[
         pantheon: Pantheon::Egyptian,
         symbols: &["Ibis"],
         titles: &["god of knowledge and wisdom"],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const ELVEN: &[Deity] = &[
     Deity {
@@ -15,11 +15,12 @@ pub const ELVEN: &[Deity] = &[
             "patron of the avariel",
             "goddess of air, rain, fertility, and birth",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Angharradh",
         alignment: Alignment::CHAOTIC_GOOD,
-        domains: &[Domain::Knowledge, Domain::Life, Domain::War],
+        domains: &[Domain::Knowledge, Domain::Life, Domain::Peace, Domain::War],
         pantheon: Pantheon::Elven,
         symbols: &[
             "Triangle with three interlocking circles within",
@@ -30,6 +31,7 @@ pub const ELVEN: &[Deity] = &[
             "the fierce mother-protector of the elf people",
             "goddess of wisdom, growth, and protection",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Alathrien Druanna",
@@ -38,6 +40,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["A quill or glyph"],
         titles: &["deity of runes, writing, and spellcasting"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Alobal Lorfiril",
@@ -46,6 +49,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Wine Glass"],
         titles: &["deity of revelry and mirth"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Araleth Letheranil",
@@ -54,6 +58,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Shaft of light"],
         titles: &["deity of light, stars, and revelations"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Corellon Larathian",
@@ -66,6 +71,19 @@ pub const ELVEN: &[Deity] = &[
             "god of elves, magic, poetry, rulership, and warcraft",
             "Primary god of elves",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::Elven, "Sehanine Moonbow"),
+                DeityRef::new(Pantheon::Elven, "Angharradh"),
+            ],
+            rivals: &[
+                DeityRef::new(Pantheon::Orc, "Gruumsh"),
+                DeityRef::new(Pantheon::Drow, "Lolth"),
+            ],
+            worshippers: &["Elves", "Half-elves", "Artists", "Bards", "Wizards"],
+            favored_weapon: Some("Longsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Darahl Tilvenar",
@@ -74,6 +92,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Flame between hands"],
         titles: &["deity of fire, earth, and metalwork"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Deep Sashelas",
@@ -86,6 +105,7 @@ pub const ELVEN: &[Deity] = &[
             "lord of the sea elves and of dolphins",
             "god of creativity, knowledge, and the sea",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Elebrin Liothiel",
@@ -94,6 +114,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Acorn"],
         titles: &["deity of abundance, gardens, and the harvest"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Erevan Ilesere",
@@ -106,6 +127,7 @@ pub const ELVEN: &[Deity] = &[
             "trickster-god",
             "god of mischief and change",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Fenmarel Mestarine",
@@ -114,6 +136,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Two peering elven eyes"],
         titles: &["god of outcasts and solitude"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gadhelyn",
@@ -122,6 +145,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Leaf-shaped arrowhead"],
         titles: &["deity of independence and outlawry"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hanali Celanil",
@@ -133,6 +157,7 @@ pub const ELVEN: &[Deity] = &[
             "goddess of love, beauty, art, and enchantment",
             "the Winsome Rose",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kirith Sotheril",
@@ -141,6 +166,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Rainbow sphere"],
         titles: &["deity of divination and illusion"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Labelas Enoreth",
@@ -153,6 +179,7 @@ pub const ELVEN: &[Deity] = &[
             "the philosopher god",
             "god of time, history, and memory",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Melira Taralen",
@@ -161,6 +188,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Lute"],
         titles: &["deity of poetry and songs"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Mythrien Sarath",
@@ -169,6 +197,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Row of three intertwined rings"],
         titles: &["deity of abjuration and mythals"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Naralis Analor",
@@ -177,6 +206,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["White dove"],
         titles: &["deity of healing, suffering, and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Rellavar Danuvien",
@@ -185,6 +215,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Spear between two circles"],
         titles: &["deity of winter and harsh weather"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Rillifane Rallathil",
@@ -199,6 +230,7 @@ pub const ELVEN: &[Deity] = &[
             "protector of druids",
             "god of nature, beasts, and the seasons",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sarula Iliene",
@@ -207,6 +239,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Three lines symbolizing waves"],
         titles: &["deity of lakes and streams"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sehanine Moonbow",
@@ -220,6 +253,11 @@ pub const ELVEN: &[Deity] = &[
             "goddess of all life's mysteries, including mysticism, prophecy, death, and dreams",
             "the Moonlit Mystery",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Elven, "Corellon Larathian")],
+            worshippers: &["Elves", "Half-elves", "Mystics", "Diviners"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Shevarash",
@@ -228,6 +266,15 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Broken arrow over a tear"],
         titles: &["god of vengeance", "god of vengeance, loss, and hatred"],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::Drow, "Lolth"),
+                DeityRef::new(Pantheon::Drow, "Vhaeraun"),
+            ],
+            worshippers: &["Elves who have lost loved ones to the drow"],
+            favored_weapon: Some("Longbow"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Solonor Thelandira",
@@ -239,6 +286,12 @@ pub const ELVEN: &[Deity] = &[
             "god of hunting, archery, and woodcraft",
             "god of archery, hunting, and survival",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Elven, "Corellon Larathian")],
+            worshippers: &["Elves", "Archers", "Hunters", "Rangers"],
+            favored_weapon: Some("Longbow"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Tarsellis Meunniduin",
@@ -247,6 +300,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Mountain with a river"],
         titles: &["deity of mountains, rivers, and wild places"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tethrin Varald\u{e9}",
@@ -255,6 +309,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Crossed swords beneath a quarter moon and above a full moon"],
         titles: &["deity of battle, and sword fighting"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Vandria Gilmadrith",
@@ -263,6 +318,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Weeping eye"],
         titles: &["deity of war, grief, justice, and vigilance"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ye'Cind",
@@ -271,6 +327,7 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Recorder"],
         titles: &["deity of music and enchantment"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Zandilar",
@@ -279,5 +336,6 @@ pub const ELVEN: &[Deity] = &[
         pantheon: Pantheon::Elven,
         symbols: &["Lips"],
         titles: &["deity of romance, lust, and dance"],
+        lore: Lore::NONE,
     },
 ];
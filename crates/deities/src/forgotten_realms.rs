@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const FORGOTTEN_REALMS: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Cloud"],
         titles: &["goddess of air"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Amaunator",
@@ -23,6 +24,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Light of Law",
             "the Yellow God",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Asmodeus",
@@ -36,6 +38,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "The Cloven",
             "Old Hoof and Horn",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Auril",
@@ -49,6 +52,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Lady Frostkiss",
             "Icedawn",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Azuth",
@@ -62,14 +66,29 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Lord of Spellcraft",
             "the First Magister",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::ForgottenRealms, "Mystra")],
+            worshippers: &["Wizards", "Teachers"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Bane",
         alignment: Alignment::LAWFUL_EVIL,
-        domains: &[Domain::War],
+        domains: &[Domain::Order, Domain::War],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Upright black right hand, thumb and fingers together"],
         titles: &["god of tyranny", "the Black Hand", "the Lord of Darkness"],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Bhaal"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Myrkul"),
+            ],
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Torm")],
+            worshippers: &["Conquerors", "Tyrants", "Zhentarim"],
+            favored_weapon: Some("Morningstar"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Beshaba",
@@ -83,6 +102,11 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Lady Doom",
             "Black Bess",
         ],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Tymora")],
+            worshippers: &["Those seeking to avert bad luck"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Bhaal",
@@ -91,6 +115,15 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Skull surrounded by a ring of blood droplets"],
         titles: &["god of murder", "the Lord of Murder"],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Bane"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Myrkul"),
+            ],
+            worshippers: &["Assassins", "Murderers"],
+            favored_weapon: Some("Dagger"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Chauntea",
@@ -104,6 +137,11 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Grain Goddess",
             "the Earthmother",
         ],
+        lore: Lore {
+            worshippers: &["Farmers", "Gardeners", "Rural folk"],
+            holy_days: &["Greengrass"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Cyric",
@@ -112,6 +150,15 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["White jawless skull on black or purple sunburst"],
         titles: &["god of lies", "the Prince of Lies", "the Dark Sun"],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Kelemvor"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Mystra"),
+            ],
+            worshippers: &["Murderers", "Liars", "The mad"],
+            favored_weapon: Some("Longsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Deneir",
@@ -125,11 +172,12 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the First Scribe",
             "the Scibe of Oghma",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Eldath",
         alignment: Alignment::NEUTRAL_GOOD,
-        domains: &[Domain::Life, Domain::Nature],
+        domains: &[Domain::Life, Domain::Nature, Domain::Peace],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Waterfall plunging into still pool"],
         titles: &[
@@ -138,6 +186,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Guardian of Groves",
             "the Mother of Waters",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gond",
@@ -151,6 +200,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Inspiration Divine",
             "the Holy Maker of All Things",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Grumbar",
@@ -159,6 +209,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Mountain"],
         titles: &["god of earth"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gwaeron Windstrom",
@@ -172,11 +223,12 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Master Tracker",
             "the Tracker Never Led Astray",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Helm",
         alignment: Alignment::LAWFUL_NEUTRAL,
-        domains: &[Domain::Life, Domain::Light],
+        domains: &[Domain::Life, Domain::Light, Domain::Twilight],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Staring eye on upright left gauntlet"],
         titles: &[
@@ -185,6 +237,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "He of the Unsleeping Eyes",
             "the Vigilant One",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hoar",
@@ -197,11 +250,12 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Doombringer",
             "Poet of Justice",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ilmater",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Life],
+        domains: &[Domain::Life, Domain::Twilight],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Hands bound at the wrist with red cord"],
         titles: &[
@@ -210,6 +264,14 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Rack-Broken Lord",
             "He Who Endures",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Tyr"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Torm"),
+            ],
+            worshippers: &["The oppressed", "The poor", "Healers"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Istishia",
@@ -218,6 +280,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Wave"],
         titles: &["god of water"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Jergal",
@@ -231,6 +294,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Pitiless One",
             "the Bleak Seneschal",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kelemvor",
@@ -243,6 +307,15 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Lord of the Dead",
             "the Judge of the Damned",
         ],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Myrkul"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Cyric"),
+            ],
+            worshippers: &["Gravediggers", "Mourners", "Undead hunters"],
+            favored_weapon: Some("Longsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Kossuth",
@@ -251,6 +324,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Flame"],
         titles: &["god of fire"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Lathander",
@@ -265,6 +339,13 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Inspiration's Dawn",
             "the Rose-and-Gold God",
         ],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Shar")],
+            worshippers: &["Farmers", "Artists", "Young people"],
+            holy_days: &["Greengrass"],
+            favored_weapon: Some("Mace"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Leira",
@@ -278,6 +359,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Mistshadow",
             "the Lady of Deception",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Lliira",
@@ -291,6 +373,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Joybringer",
             "the Mistress of Revels",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Loviatar",
@@ -304,6 +387,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Scourge Mistress",
             "the Willing Whip",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Malar",
@@ -312,6 +396,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Clawed paw"],
         titles: &["god of the hunt", "the Beastlord", "the Black-Blooded One"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Mask",
@@ -324,6 +409,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Lord of Shadows",
             "the Master of All Thieves",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Mielikki",
@@ -336,6 +422,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Our Lady of the Forest",
             "the Forest Queen",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Milil",
@@ -348,6 +435,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Lord of Song",
             "the One True Hand of All-Wise Oghma",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Myrkul",
@@ -361,6 +449,16 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Old Lord Skull",
             "the Reaper",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Bane"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Bhaal"),
+            ],
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Kelemvor")],
+            worshippers: &["Necromancers", "Undead"],
+            favored_weapon: Some("Scythe"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Mystra",
@@ -378,6 +476,18 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Our Lady of Spells",
             "the Mother of All Magic",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Azuth"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Sel\u{fb}ne"),
+            ],
+            rivals: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Shar"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Cyric"),
+            ],
+            worshippers: &["Wizards", "Sorcerers", "Sages"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Oghma",
@@ -386,6 +496,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Blank scroll"],
         titles: &["god of knowledge", "the Binder", "the Lord of Knowledge"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "The Red Knight",
@@ -399,6 +510,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Crimson General",
             "the Grandmaster of the Lanceboard",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Savras",
@@ -412,11 +524,12 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Third Eye",
             "Divination's Lord",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sel\u{fb}ne",
         alignment: Alignment::CHAOTIC_GOOD,
-        domains: &[Domain::Knowledge, Domain::Life],
+        domains: &[Domain::Knowledge, Domain::Life, Domain::Twilight],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Pair of eyes surrounded by seven stars"],
         titles: &[
@@ -425,6 +538,13 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Moonmaiden",
             "the Night White Lady",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::ForgottenRealms, "Mystra")],
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Shar")],
+            worshippers: &["Navigators", "Lycanthropes", "Those who travel at night"],
+            favored_weapon: Some("Mace"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Shar",
@@ -438,6 +558,16 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Dark Lady",
             "Our Lady of Loss",
         ],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Sel\u{fb}ne"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Lathander"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Mystra"),
+            ],
+            worshippers: &["Assassins", "The bereaved", "The embittered"],
+            favored_weapon: Some("Chakram"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Silvanus",
@@ -451,6 +581,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Old Oak",
             "Old Father Tree",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sune",
@@ -464,6 +595,11 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Lady of Love",
             "the Princess of Passion",
         ],
+        lore: Lore {
+            worshippers: &["Lovers", "Artists", "Half-elves"],
+            holy_days: &["Midsummer"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Talona",
@@ -477,6 +613,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "Mistress of Disease",
             "the Plague-crone",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Talos",
@@ -485,6 +622,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Three lightning bolts radiating from a central point"],
         titles: &["god of storms", "Stormlord", "the Destroyer"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tempus",
@@ -493,6 +631,11 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Upright flaming sword"],
         titles: &["god of war", "the Foehammer", "the Lord of Battles"],
+        lore: Lore {
+            worshippers: &["Warriors", "Mercenaries", "Soldiers"],
+            favored_weapon: Some("Battleaxe"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Torm",
@@ -506,6 +649,16 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the True",
             "the Hand of Righteousness",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Tyr"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Ilmater"),
+            ],
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Bane")],
+            worshippers: &["Paladins", "Guards", "Knights"],
+            favored_weapon: Some("Greatsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Tymora",
@@ -514,11 +667,17 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Face-up coin"],
         titles: &["goddess of good fortune", "Lady Luck", "Our Smiling Lady"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::ForgottenRealms, "Beshaba")],
+            worshippers: &["Adventurers", "Gamblers", "Rogues"],
+            favored_weapon: Some("Shuriken"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Tyr",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::War],
+        domains: &[Domain::Order, Domain::War],
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["Balanced scales resting on a warhammer"],
         titles: &[
@@ -527,6 +686,15 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Maimed God",
             "the Evenhanded",
         ],
+        lore: Lore {
+            allies: &[
+                DeityRef::new(Pantheon::ForgottenRealms, "Torm"),
+                DeityRef::new(Pantheon::ForgottenRealms, "Ilmater"),
+            ],
+            worshippers: &["Judges", "Paladins", "Magistrates"],
+            favored_weapon: Some("Longsword"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Umberlee",
@@ -540,6 +708,10 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Queen of the Depths",
             "the Wavemother",
         ],
+        lore: Lore {
+            worshippers: &["Sailors", "Those who fear the sea"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Valkur",
@@ -548,6 +720,7 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
         pantheon: Pantheon::ForgottenRealms,
         symbols: &["A cloud and three lightning bolts"],
         titles: &["Northlander god of sailors"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Waukeen",
@@ -561,5 +734,6 @@ pub const FORGOTTEN_REALMS: &[Deity] = &[
             "the Coinmaiden",
             "the Merchant's Friend",
         ],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const GIANT: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const GIANT: &[Deity] = &[
         pantheon: Pantheon::Giant,
         symbols: &["Wooden club"],
         titles: &["hill giant god of war"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Skoraeus Stonebones",
@@ -18,6 +19,7 @@ pub const GIANT: &[Deity] = &[
         pantheon: Pantheon::Giant,
         symbols: &["Stalactite"],
         titles: &["god of stone giants and art"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Surtur",
@@ -26,6 +28,11 @@ pub const GIANT: &[Deity] = &[
         pantheon: Pantheon::Giant,
         symbols: &["Flaming sword"],
         titles: &["god of fire giants and craft"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Giant, "Thrym")],
+            worshippers: &["Fire giants"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Thrym",
@@ -34,5 +41,10 @@ pub const GIANT: &[Deity] = &[
         pantheon: Pantheon::Giant,
         symbols: &["White double-bladed axe"],
         titles: &["god of frost giants and strength"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Giant, "Surtur")],
+            worshippers: &["Frost giants"],
+            ..Lore::NONE
+        },
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const GNOMISH: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Face of a raccoon"],
         titles: &["god of forests and woodlands", "god of woodlands"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Baravar Cloakshadow",
@@ -18,6 +19,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Dagger against a hooded cloak"],
         titles: &["god of illusion and deception", "Sly One"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Bleredd",
@@ -26,6 +28,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Iron mule"],
         titles: &["deity of labor and craft"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Callarduran Smoothhands",
@@ -34,6 +37,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Golden signet ring with a six-pointed star"],
         titles: &["god of mining and carving stone"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Flandal Steelskin",
@@ -47,14 +51,16 @@ pub const GNOMISH: &[Deity] = &[
             "god of physical improvement and good health",
             "the Steelsmith",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Gaerdal Ironhand",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::War],
+        domains: &[Domain::Peace, Domain::War],
         pantheon: Pantheon::Gnomish,
         symbols: &["Iron band"],
         titles: &["god of protection", "god of war, vigilance, and defense"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Garl Glittergold",
@@ -69,6 +75,12 @@ pub const GNOMISH: &[Deity] = &[
             "the king of the gnomish gods",
             "Primary god of gnomes",
         ],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Kobold, "Kurtulmak")],
+            worshippers: &["Gnomes", "Jesters", "Gemcutters"],
+            favored_weapon: Some("Battleaxe"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Gelf Darkhearth",
@@ -77,6 +89,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Broken anvil"],
         titles: &["deity of frustration and destruction"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Nebelun",
@@ -85,6 +98,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Bellows and a lizard tail"],
         titles: &["god of invention and luck", "the Meddler"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Rill Cleverthrush",
@@ -93,6 +107,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Interlocking gears"],
         titles: &["deity of law and thought"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Segojan Earthcaller",
@@ -105,6 +120,7 @@ pub const GNOMISH: &[Deity] = &[
             "god of the wilds beneath the earth",
             "god of the burrows",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sheyanna Flaxenstrand",
@@ -113,6 +129,7 @@ pub const GNOMISH: &[Deity] = &[
         pantheon: Pantheon::Gnomish,
         symbols: &["Two silver goblets"],
         titles: &["deity of love, beauty, and passion"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Urdlen",
@@ -124,5 +141,6 @@ pub const GNOMISH: &[Deity] = &[
             "god of greed and murder",
             "great-clawed god of bloodlust and evil, of greed and uncontrolled impulses",
         ],
+        lore: Lore::NONE,
     },
 ];
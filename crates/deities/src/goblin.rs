@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const GOBLIN: &[Deity] = &[Deity {
     name: "Maglubiyet",
@@ -8,5 +8,11 @@ pub const GOBLIN: &[Deity] = &[Deity {
     domains: &[Domain::War],
     symbols: &["Bloody axe"],
     titles: &["god of war"],
+    lore: Lore {
+        rivals: &[DeityRef::new(Pantheon::Orc, "Gruumsh")],
+        worshippers: &["Goblins", "Hobgoblins", "Bugbears"],
+        favored_weapon: Some("Battleaxe"),
+        ..Lore::NONE
+    },
     pantheon: Pantheon::Goblin,
 }];
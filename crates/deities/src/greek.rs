@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const GREEK: &[Deity] = &[
     Deity {
@@ -10,6 +10,11 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Fist full of lightning bolts"],
         titles: &["god of the sky, ruler of the gods"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Greek, "Hera")],
+            worshippers: &["Rulers", "Judges"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Aphrodite",
@@ -18,6 +23,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Sea shell"],
         titles: &["goddess of love and beauty"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Apollo",
@@ -26,6 +32,12 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Lyre"],
         titles: &["god of light, music, and healing"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Greek, "Artemis")],
+            worshippers: &["Musicians", "Healers", "Poets"],
+            favored_weapon: Some("Longbow"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Ares",
@@ -34,6 +46,12 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Spear"],
         titles: &["god of war and strife"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Greek, "Athena")],
+            worshippers: &["Soldiers", "Warriors"],
+            favored_weapon: Some("Spear"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Artemis",
@@ -42,14 +60,26 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Bow and arrow on lunar disk"],
         titles: &["goddess of hunting and childbirth"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Greek, "Apollo")],
+            worshippers: &["Hunters", "Rangers", "Young women"],
+            favored_weapon: Some("Longbow"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Athena",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Knowledge, Domain::War],
+        domains: &[Domain::Knowledge, Domain::Order, Domain::War],
         pantheon: Pantheon::Greek,
         symbols: &["Owl"],
         titles: &["goddess of wisdom and civilization"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Greek, "Ares")],
+            worshippers: &["Scholars", "Craftspeople", "Generals"],
+            favored_weapon: Some("Spear"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Demeter",
@@ -58,6 +88,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Mare's head"],
         titles: &["goddess of agriculture"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Dionysus",
@@ -66,6 +97,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Thyrsus (staff tipped with pine cone)"],
         titles: &["god of mirth and wine"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hades",
@@ -74,14 +106,16 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Black ram"],
         titles: &["god of the underworld"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hecate",
         alignment: Alignment::CHAOTIC_EVIL,
-        domains: &[Domain::Knowledge, Domain::Trickery],
+        domains: &[Domain::Knowledge, Domain::Trickery, Domain::Twilight],
         pantheon: Pantheon::Greek,
         symbols: &["Setting moon"],
         titles: &["goddess of magic and the moon"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hephaestus",
@@ -90,6 +124,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Hammer and anvil"],
         titles: &["god of smithing and craft"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hera",
@@ -98,6 +133,11 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Fan of peacock feathers"],
         titles: &["goddess of marriage and intrigue"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Greek, "Zeus")],
+            worshippers: &["Married women", "Families"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Hercules",
@@ -106,6 +146,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Lion's head"],
         titles: &["god of strength and adventure"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hermes",
@@ -114,14 +155,16 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Caduceus (winged staff and serpents)"],
         titles: &["god of travel and commerce"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hestia",
         alignment: Alignment::NEUTRAL_GOOD,
-        domains: &[Domain::Life],
+        domains: &[Domain::Life, Domain::Peace],
         pantheon: Pantheon::Greek,
         symbols: &["Hearth"],
         titles: &["goddess of home and family"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Nike",
@@ -130,6 +173,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Winged woman"],
         titles: &["goddess of victory"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Pan",
@@ -138,6 +182,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Syrinx (pan pipes)"],
         titles: &["god of nature"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Poseidon",
@@ -146,6 +191,7 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Trident"],
         titles: &["god of the sea and earthquakes"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tyche",
@@ -154,5 +200,6 @@ pub const GREEK: &[Deity] = &[
         pantheon: Pantheon::Greek,
         symbols: &["Red pentagram"],
         titles: &["goddess of good fortune"],
+        lore: Lore::NONE,
     },
 ];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const GREYHAWK: &[Deity] = &[
     Deity {
@@ -10,6 +10,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Green disk"],
         titles: &["goddess of nature"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Boccob",
@@ -18,14 +19,16 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Eye within a pentagram"],
         titles: &["god of magic"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Celestian",
         alignment: Alignment::NEUTRAL,
-        domains: &[Domain::Knowledge],
+        domains: &[Domain::Knowledge, Domain::Twilight],
         pantheon: Pantheon::Greyhawk,
         symbols: &["Arc of seven stars inside a circle"],
         titles: &["god of stars and wanderers"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ehlonna",
@@ -34,6 +37,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Unicorn horn"],
         titles: &["goddess of woodlands"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Erythnul",
@@ -42,6 +46,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Blood drop"],
         titles: &["god of envy and slaughter"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Fharlanghn",
@@ -50,6 +55,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Circle crossed by a curved horizon line"],
         titles: &["god of horizons and travel"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Heironeous",
@@ -58,6 +64,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Lightning bolt"],
         titles: &["god of chivalry and valor"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hextor",
@@ -66,6 +73,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Six arrows facing downward in a fan"],
         titles: &["god of war and discord"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Kord",
@@ -74,6 +82,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Four spears and four maces radiating out from a central point"],
         titles: &["god of athletics and sport"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Incabulos",
@@ -82,6 +91,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Reptilian eye with a horizontal diamond"],
         titles: &["god of plague and famine"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Istus",
@@ -90,6 +100,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Weaver's spindle with three strands"],
         titles: &["goddess of fate and destiny"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Iuz",
@@ -98,6 +109,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Grinning human skull"],
         titles: &["god of pain and oppression"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Nerull",
@@ -106,6 +118,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Skull with either a sickle or a scythe"],
         titles: &["god of death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Obad-Hai",
@@ -114,6 +127,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Oak leaf and acorn"],
         titles: &["god of nature"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Olidammara",
@@ -122,6 +136,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Laughing mask"],
         titles: &["god of revelry"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Pelor",
@@ -130,14 +145,16 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Sun"],
         titles: &["god of the sun and healing"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Pholtus",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Light],
+        domains: &[Domain::Light, Domain::Order],
         pantheon: Pantheon::Greyhawk,
         symbols: &["Silver sun or full moon partially eclipsed by a smaller crescent moon"],
         titles: &["god of light and law"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ralishaz",
@@ -146,14 +163,16 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Three bone fate-casting sticks"],
         titles: &["god of ill luck and insanity"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Rao",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Knowledge],
+        domains: &[Domain::Knowledge, Domain::Peace],
         pantheon: Pantheon::Greyhawk,
         symbols: &["White heart"],
         titles: &["god of peace and reason"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "St. Cuthbert",
@@ -162,6 +181,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Circle at the center of a starburst of lines"],
         titles: &["god of common sense and zeal"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tharizdun",
@@ -170,6 +190,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Dark spiral or inverted ziggurat"],
         titles: &["god of eternal darkness"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Trithereon",
@@ -178,6 +199,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Triskelion"],
         titles: &["god of liberty and retribution"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Ulaa",
@@ -186,6 +208,7 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Mountain with a circle at its heart"],
         titles: &["goddess of hills and mountains"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Vecna",
@@ -194,13 +217,15 @@ pub const GREYHAWK: &[Deity] = &[
         pantheon: Pantheon::Greyhawk,
         symbols: &["Hand with eye in the palm"],
         titles: &["god of evil secrets"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Wee Jas",
         alignment: Alignment::LAWFUL_NEUTRAL,
-        domains: &[Domain::Death, Domain::Knowledge],
+        domains: &[Domain::Death, Domain::Knowledge, Domain::Order],
         pantheon: Pantheon::Greyhawk,
         symbols: &["Red skull in front of fireball"],
         titles: &["goddess of magic and death"],
+        lore: Lore::NONE,
     },
 ];
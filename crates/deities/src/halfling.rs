@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const HALFLING: &[Deity] = &[
     Deity {
@@ -14,6 +14,7 @@ pub const HALFLING: &[Deity] = &[
             "defender-god",
             "watchful protector",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Brandobaris",
@@ -25,6 +26,7 @@ pub const HALFLING: &[Deity] = &[
             "god of thievery, stealth, and adventure",
             "god of adventure and thievery",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Charmalaine",
@@ -33,6 +35,7 @@ pub const HALFLING: &[Deity] = &[
         pantheon: Pantheon::Halfling,
         symbols: &["Burning boot print"],
         titles: &["deity of keen senses and luck"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Cyrrollalee",
@@ -45,6 +48,7 @@ pub const HALFLING: &[Deity] = &[
             "goddess of trust and handicrafts",
             "goddess of hearth and home",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sheela Peryroyl",
@@ -60,6 +64,7 @@ pub const HALFLING: &[Deity] = &[
             "the Green Sister of Yondalla",
             "goddess of agriculture, nature, and weather",
         ],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Urogalan",
@@ -68,11 +73,12 @@ pub const HALFLING: &[Deity] = &[
         pantheon: Pantheon::Halfling,
         symbols: &["Silhouette of a dog's head"],
         titles: &["god of earth and death"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Yondalla",
         alignment: Alignment::LAWFUL_GOOD,
-        domains: &[Domain::Life],
+        domains: &[Domain::Life, Domain::Twilight],
         pantheon: Pantheon::Halfling,
         symbols: &["Cornucopia on a shield", "shield", "cornucopia"],
         titles: &[
@@ -81,5 +87,10 @@ pub const HALFLING: &[Deity] = &[
             "the Blessed One",
             "Primary goddess of halflings",
         ],
+        lore: Lore {
+            worshippers: &["Halflings", "Families"],
+            favored_weapon: Some("Shortsword"),
+            ..Lore::NONE
+        },
     },
 ];
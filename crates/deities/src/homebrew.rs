@@ -0,0 +1,442 @@
+//! # Homebrew Pantheons
+//!
+//! Pantheons that aren't built in, such as the gods of a particular campaign,
+//! loaded from TOML or JSON data files. Each file describes a single pantheon:
+//!
+//! ```toml
+//! name = "The Shattered Court"
+//!
+//! [[deities]]
+//! name = "Velis"
+//! alignment = "LG"
+//! domains = ["Order", "Light"]
+//! symbols = ["A cracked crown"]
+//! titles = ["the Last Judge"]
+//! # Optional lore
+//! rivals = [{ name = "Morrow" }, { pantheon = "Greek", name = "Ares" }]
+//! worshippers = ["Magistrates", "Jailers"]
+//! holy_days = ["The Day of Scales"]
+//! favored_weapon = "Warhammer"
+//!
+//! [[deities]]
+//! name = "Morrow"
+//! alignment = "CN"
+//! rivals = [{ name = "Velis" }]
+//! ```
+//!
+//! Allies and rivals without a pantheon refer to deities in the same file.
+//! Names are matched ignoring case.
+//!
+//! Once loaded, homebrew pantheons are listed and filtered alongside the
+//! built-in ones. They are only generated from when asked for as a likely
+//! pantheon, so loading them doesn't change the results of existing seeds.
+//!
+//! Loaded pantheons live for the rest of the program, so this should happen
+//! once at startup. Their strings are interned, so each distinct string is
+//! only kept once.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, RwLock},
+};
+
+use alignments::Alignment;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
+
+/// A loaded homebrew pantheon's name and deities
+type Loaded = (&'static str, &'static [Deity]);
+
+/// Homebrew pantheons that have been loaded, by name
+static PANTHEONS: RwLock<Vec<Loaded>> = RwLock::new(Vec::new());
+
+/// Strings used by loaded pantheons, so that each distinct string is only
+/// kept once
+static STRINGS: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Homebrew pantheons that have been loaded, in the order they were loaded
+pub(crate) fn pantheons() -> impl Iterator<Item = Pantheon> {
+    PANTHEONS
+        .read()
+        .expect("homebrew pantheons lock shouldn't be poisoned")
+        .iter()
+        .map(|&(name, _)| Pantheon::Homebrew { name })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// Deities of a loaded homebrew pantheon. Empty if it hasn't been loaded.
+pub(crate) fn deities(name: &str) -> &'static [Deity] {
+    PANTHEONS
+        .read()
+        .expect("homebrew pantheons lock shouldn't be poisoned")
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map_or(&[], |&(_, deities)| deities)
+}
+
+/// A homebrew pantheon, as described in a data file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PantheonData {
+    /// Name of the pantheon. Can't be the same as any other pantheon.
+    pub name: String,
+    /// Deities that are part of this pantheon
+    pub deities: Vec<DeityData>,
+}
+
+/// A homebrew deity, with the same fields as a [`Deity`]. The pantheon is
+/// taken from the file it is in.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeityData {
+    /// Name the deity is called by
+    pub name: String,
+    /// Alignment of the deity, such as "Lawful Good" or "LG"
+    pub alignment: Alignment,
+    /// Domains this deity is responsible for
+    #[serde(default)]
+    pub domains: Vec<Domain>,
+    /// Symbols that are used to represent this deity
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// Different titles the deity is also known by
+    #[serde(default)]
+    pub titles: Vec<String>,
+    /// Deities this deity is allied with
+    #[serde(default)]
+    pub allies: Vec<DeityRefData>,
+    /// Deities this deity is opposed to
+    #[serde(default)]
+    pub rivals: Vec<DeityRefData>,
+    /// Peoples and professions that typically worship this deity
+    #[serde(default)]
+    pub worshippers: Vec<String>,
+    /// Days and festivals held sacred by followers of this deity
+    #[serde(default)]
+    pub holy_days: Vec<String>,
+    /// Weapon favored by this deity and their clerics
+    pub favored_weapon: Option<String>,
+}
+
+/// Reference to another deity, as described in a data file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeityRefData {
+    /// Pantheon the deity is a part of. The same pantheon if not given.
+    pub pantheon: Option<Pantheon>,
+    /// Name of the deity within the pantheon
+    pub name: String,
+}
+
+impl PantheonData {
+    /// Parse a pantheon from a `.toml` or `.json` file
+    ///
+    /// # Errors
+    ///
+    /// Will error if the file can't be read, isn't TOML or JSON, or doesn't
+    /// describe a pantheon
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, HomebrewError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|source| HomebrewError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let parse_error = |message: String| HomebrewError::Parse {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        match path.extension().and_then(OsStr::to_str) {
+            Some("toml") => toml::from_str(&contents).map_err(|e| parse_error(e.to_string())),
+            Some("json") => serde_json::from_str(&contents).map_err(|e| parse_error(e.to_string())),
+            _ => Err(HomebrewError::UnsupportedFile(path.to_path_buf())),
+        }
+    }
+
+    /// Check that the pantheon and its deities are complete. References to
+    /// other homebrew pantheons are looked up in `loaded`.
+    fn validate(&self, loaded: &[Loaded]) -> Result<(), HomebrewError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(HomebrewError::MissingPantheonName);
+        }
+        if self.deities.is_empty() {
+            return Err(HomebrewError::NoDeities(name.to_string()));
+        }
+
+        let mut names = HashSet::new();
+        for deity in &self.deities {
+            let deity_name = deity.name.trim();
+            if deity_name.is_empty() {
+                return Err(HomebrewError::MissingDeityName(name.to_string()));
+            }
+            if !names.insert(deity_name.to_lowercase()) {
+                return Err(HomebrewError::DuplicateDeity {
+                    pantheon: name.to_string(),
+                    deity: deity_name.to_string(),
+                });
+            }
+        }
+
+        let references = self
+            .deities
+            .iter()
+            .flat_map(|d| d.allies.iter().chain(&d.rivals));
+        for reference in references {
+            if self.resolve(reference, loaded).is_none() {
+                return Err(HomebrewError::UnknownDeity {
+                    pantheon: reference
+                        .pantheon
+                        .map_or_else(|| name.to_string(), |p| p.to_string()),
+                    deity: reference.name.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Name of the deity a reference points to, as it is written where the
+    /// deity is defined. Names are matched ignoring case. References to other
+    /// homebrew pantheons are looked up in `loaded`, since the lock on them is
+    /// already held while loading.
+    fn resolve(&self, reference: &DeityRefData, loaded: &[Loaded]) -> Option<&str> {
+        let name = reference.name.trim();
+        let find = |deities: &[Deity]| {
+            deities
+                .iter()
+                .find(|d| d.name.eq_ignore_ascii_case(name))
+                .map(|d| d.name)
+        };
+        match &reference.pantheon {
+            Some(Pantheon::Homebrew { name: pantheon }) => loaded
+                .iter()
+                .find(|(n, _)| n == pantheon)
+                .and_then(|(_, deities)| find(deities)),
+            Some(pantheon) => find(&pantheon.deities(None)),
+            None => self
+                .deities
+                .iter()
+                .map(|d| d.name.trim())
+                .find(|n| n.eq_ignore_ascii_case(name)),
+        }
+    }
+
+    /// Validate and load this pantheon, so that it is used alongside the
+    /// built-in pantheons.
+    ///
+    /// ```
+    /// use deities::{homebrew::PantheonData, Deity, Pantheon};
+    ///
+    /// let data: PantheonData = serde_json::from_str(
+    ///     r#"{"name": "Doc Gods", "deities": [{"name": "Scribe", "alignment": "LN"}]}"#,
+    /// )?;
+    /// let pantheon = data.load()?;
+    ///
+    /// assert_eq!(pantheon.to_string(), "Doc Gods");
+    /// assert_eq!(pantheon.deities(None)[0].name, "Scribe");
+    /// assert_eq!("doc gods".parse(), Ok(pantheon));
+    /// assert_eq!(Deity::named("scribe").count(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if the pantheon or its deities are missing a name, or if
+    /// they have the same name as another pantheon or deity
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock on loaded pantheons is poisoned
+    pub fn load(&self) -> Result<Pantheon, HomebrewError> {
+        let pantheons = load_all(std::slice::from_ref(self))?;
+        Ok(pantheons[0])
+    }
+
+    /// Deities of this pantheon, kept for the rest of the program. Should
+    /// only be called once the pantheon is validated and known to be new, so
+    /// that the deities of each pantheon are only leaked once.
+    fn leak_deities(&self, pantheon: Pantheon, loaded: &[Loaded]) -> &'static [Deity] {
+        let reference = |r: &DeityRefData| DeityRef {
+            pantheon: r.pantheon.unwrap_or(pantheon),
+            name: intern(self.resolve(r, loaded).expect("references are validated")),
+        };
+        let strings = |strings: &[String]| -> &'static [&'static str] {
+            Vec::leak(strings.iter().map(|s| intern(s)).collect())
+        };
+        let deities = self
+            .deities
+            .iter()
+            .map(|deity| Deity {
+                name: intern(deity.name.trim()),
+                alignment: deity.alignment,
+                domains: Vec::leak(deity.domains.clone()),
+                pantheon,
+                symbols: strings(&deity.symbols),
+                titles: strings(&deity.titles),
+                lore: Lore {
+                    allies: Vec::leak(deity.allies.iter().map(reference).collect()),
+                    rivals: Vec::leak(deity.rivals.iter().map(reference).collect()),
+                    worshippers: strings(&deity.worshippers),
+                    holy_days: strings(&deity.holy_days),
+                    favored_weapon: deity.favored_weapon.as_deref().map(intern),
+                },
+            })
+            .collect::<Vec<_>>();
+        Vec::leak(deities)
+    }
+}
+
+/// Validate and load several pantheons at once. If any of them is invalid,
+/// none of them are loaded.
+///
+/// # Errors
+///
+/// Will error if any pantheon or its deities are missing a name, or if they
+/// have the same name as another pantheon or deity
+///
+/// # Panics
+///
+/// Panics if the lock on loaded pantheons is poisoned
+pub fn load_all(pantheons: &[PantheonData]) -> Result<Vec<Pantheon>, HomebrewError> {
+    // Hold the lock while checking for duplicates so two pantheons with the
+    // same name can't be loaded at once
+    let mut loaded = PANTHEONS
+        .write()
+        .expect("homebrew pantheons lock shouldn't be poisoned");
+    for (i, pantheon) in pantheons.iter().enumerate() {
+        pantheon.validate(&loaded)?;
+        let name = pantheon.name.trim();
+        if Pantheon::iter().any(|p| p.to_string().eq_ignore_ascii_case(name))
+            || loaded.iter().any(|(n, _)| n.eq_ignore_ascii_case(name))
+            || pantheons[..i]
+                .iter()
+                .any(|p| p.name.trim().eq_ignore_ascii_case(name))
+        {
+            return Err(HomebrewError::DuplicatePantheon(name.to_string()));
+        }
+    }
+
+    // Only reached once every pantheon is known to be valid and new
+    Ok(pantheons
+        .iter()
+        .map(|data| {
+            let name = intern(data.name.trim());
+            let pantheon = Pantheon::Homebrew { name };
+            let deities = data.leak_deities(pantheon, &loaded);
+            loaded.push((name, deities));
+            pantheon
+        })
+        .collect())
+}
+
+/// Deities are referenced for the rest of the program, so the strings they
+/// were loaded from are kept around as well. Each distinct string is only
+/// kept once, however many pantheons use it.
+fn intern(s: &str) -> &'static str {
+    let mut strings = STRINGS
+        .lock()
+        .expect("homebrew strings lock shouldn't be poisoned");
+    if let Some(&interned) = strings.get(s) {
+        interned
+    } else {
+        let interned = String::leak(s.to_string());
+        strings.insert(interned);
+        interned
+    }
+}
+
+/// Load every `.toml` and `.json` file in a directory as a homebrew pantheon.
+/// Files are loaded in order of their names, so that seeded results are
+/// consistent as long as the same files are loaded. If any file is invalid,
+/// none of them are loaded.
+///
+/// # Errors
+///
+/// Will error if the directory or any of its files can't be read, or if any
+/// of the pantheons are invalid
+pub fn load_dir(path: impl AsRef<Path>) -> Result<Vec<Pantheon>, HomebrewError> {
+    let path = path.as_ref();
+    let io_error = |source| HomebrewError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut files = fs::read_dir(path)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    files.retain(|file| {
+        file.is_file()
+            && matches!(
+                file.extension().and_then(OsStr::to_str),
+                Some("toml" | "json")
+            )
+    });
+    files.sort();
+
+    let pantheons = files
+        .into_iter()
+        .map(PantheonData::from_file)
+        .collect::<Result<Vec<_>, _>>()?;
+    load_all(&pantheons)
+}
+
+/// Errors caused by loading an invalid homebrew pantheon
+#[derive(Debug, Error)]
+pub enum HomebrewError {
+    /// File or directory couldn't be read
+    #[error("Couldn't read {path}: {source}")]
+    Io {
+        /// Path that was being read
+        path: PathBuf,
+        /// Underlying error
+        source: io::Error,
+    },
+    /// File doesn't describe a valid pantheon
+    #[error("{path} isn't a valid pantheon: {message}")]
+    Parse {
+        /// Path of the invalid file
+        path: PathBuf,
+        /// Description of what is wrong with the file
+        message: String,
+    },
+    /// File isn't a supported format
+    #[error("{0} isn't a supported file type. Please use a .toml or .json file.")]
+    UnsupportedFile(PathBuf),
+    /// Pantheon doesn't have a name
+    #[error("Every pantheon needs a name.")]
+    MissingPantheonName,
+    /// Pantheon has the same name as another pantheon
+    #[error("There is already a pantheon named {0}. Please choose a different name.")]
+    DuplicatePantheon(String),
+    /// Pantheon doesn't have any deities
+    #[error("The {0} pantheon doesn't have any deities. Please add at least one.")]
+    NoDeities(String),
+    /// Deity doesn't have a name
+    #[error("Every deity in the {0} pantheon needs a name.")]
+    MissingDeityName(String),
+    /// Deity has the same name as another deity in the pantheon
+    #[error("The {pantheon} pantheon has more than one deity named {deity}.")]
+    DuplicateDeity {
+        /// Name of the pantheon
+        pantheon: String,
+        /// Name of the deity
+        deity: String,
+    },
+    /// Ally or rival isn't a deity in the pantheon it refers to
+    #[error("There is no deity named {deity} in the {pantheon} pantheon.")]
+    UnknownDeity {
+        /// Name of the pantheon
+        pantheon: String,
+        /// Name of the deity
+        deity: String,
+    },
+}
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const KOBOLD: &[Deity] = &[Deity {
     name: "Kurtulmak",
@@ -9,4 +9,10 @@ pub const KOBOLD: &[Deity] = &[Deity {
     pantheon: Pantheon::Kobold,
     symbols: &["Gnome skull"],
     titles: &["god of war and mining"],
+    lore: Lore {
+        rivals: &[DeityRef::new(Pantheon::Gnomish, "Garl Glittergold")],
+        worshippers: &["Kobolds"],
+        favored_weapon: Some("Spear"),
+        ..Lore::NONE
+    },
 }];
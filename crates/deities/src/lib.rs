@@ -14,19 +14,23 @@
     unused
 )]
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap, fmt, str::FromStr};
 
 use alignments::{Alignment, AlignmentInfluences, Attitude, Morality};
 use dice::Die;
+use once_cell::sync::Lazy;
 use rand::{
     distributions::Standard,
     prelude::Distribution,
     seq::{IteratorRandom, SliceRandom},
     Rng,
 };
-use rand_utils::SliceExpRandom;
-use serde::{Deserialize, Serialize};
+use rand_utils::{GeneratorVersion, SliceExpRandom, UNLIKELY_PENALTY};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use strum::{Display, EnumIter, IntoEnumIterator};
+use thiserror::Error;
+
+pub use crate::patrons::{Patron, PatronKind};
 
 mod bugbear;
 mod celtic;
@@ -45,10 +49,12 @@ mod goblin;
 mod greek;
 mod greyhawk;
 mod halfling;
+pub mod homebrew;
 mod kobold;
 mod lizardfolk;
 mod norse;
 mod orc;
+mod patrons;
 
 /// In a pantheon, every deity has influence over different aspects of mortal
 /// life and civilization, called a deity’s domain. All the domains over which
@@ -64,7 +70,7 @@ mod orc;
 /// association with the Life domain. Alternatively, your choice of domain
 /// could simply be a matter of personal preference, the aspect of the deity
 /// that appeals to you most.
-#[derive(Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
 pub enum Domain {
     /// Magic is an energy that suffuses the multiverse and that fuels both
     /// destruction and creation. Gods of the Arcana domain know the secrets
@@ -160,6 +166,28 @@ pub enum Domain {
     /// woodlands, bless the harvest of the faithful, or wither the crops of
     /// those who anger their gods.
     Nature,
+    /// The Order domain represents discipline, as well as devotion to the laws
+    /// that govern a society, an institution, or a philosophy. Clerics of
+    /// Order meditate on logic and justice as they serve their gods. They
+    /// believe that well-crafted laws establish legitimate hierarchies, and
+    /// those selected by law to lead must be obeyed. Those who obey must do so
+    /// to the best of their ability, and if those who lead fail to protect the
+    /// law, they must be replaced. In this manner, law weaves a web of
+    /// obligations that create order and security in a chaotic multiverse.
+    /// Deities of this domain include Aureon, Bane, Majere, Pholtus, Tyr, and
+    /// Wee Jas.
+    Order,
+    /// The balm of peace thrives at the heart of healthy communities, between
+    /// friendly nations, and in the souls of the kindhearted. The gods of
+    /// peace inspire people of all sorts to resolve conflict and to stand up
+    /// against those forces that try to prevent peace from flourishing.
+    /// Clerics of the Peace domain preside over the signing of treaties, and
+    /// they are often asked to arbitrate in disputes. These clerics' blessings
+    /// draw people together and help them shoulder one another's burdens,
+    /// and the clerics' magic aids those who are driven to fight for the way
+    /// of peace. Deities of this domain include Angharradh, Berronar
+    /// Truesilver, Boldrei, Eldath, Gaerdal Ironhand, Paladine, and Rao.
+    Peace,
     /// Gods whose portfolios include the Tempest domain — including Talos,
     /// Umberlee, Kord, Zeboim, the Devourer, Zeus, and Thor — govern storms,
     /// sea, and sky. They include gods of lightning and thunder, gods of
@@ -181,6 +209,15 @@ pub enum Domain {
     /// captives, and flouting hollow traditions. They prefer subterfuge,
     /// pranks, deception, and theft rather than direct confrontation.
     Trickery,
+    /// The twilit transition from light into darkness often brings calm and
+    /// even joy, as the day's labors end and the hours of rest begin. The
+    /// darkness can also bring terrors, but the gods of twilight guard
+    /// against the horrors of the night. Clerics who serve these deities
+    /// bring comfort to those who seek rest and protect them by venturing
+    /// into the encroaching darkness to ensure that the dark is a comfort,
+    /// not a terror. Deities of this domain include Boldrei, Celestian, Dol
+    /// Arrah, Helm, Ilmater, Mishakal, Selûne, and Yondalla.
+    Twilight,
     /// War has many manifestations. It can make heroes of ordinary people. It
     /// can be desperate and horrific, with acts of cruelty and cowardice
     /// eclipsing instances of excellence and courage. In either case, the gods
@@ -196,9 +233,52 @@ pub enum Domain {
     War,
 }
 
-impl Distribution<Domain> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Domain {
-        let domain = Domain::iter().choose(rng).unwrap();
+impl Domain {
+    /// Domains that could be chosen before the Order, Peace, and Twilight
+    /// domains were added
+    const V1: &'static [Self] = &[
+        Self::Arcana,
+        Self::Death,
+        Self::Forge,
+        Self::Grave,
+        Self::Knowledge,
+        Self::Life,
+        Self::Light,
+        Self::Nature,
+        Self::Tempest,
+        Self::Trickery,
+        Self::War,
+    ];
+
+    /// Whether a specific version of the generator knows about this domain
+    ///
+    /// ```
+    /// use deities::Domain;
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// assert!(Domain::Life.is_in_version(GeneratorVersion::V1));
+    /// assert!(!Domain::Order.is_in_version(GeneratorVersion::V1));
+    /// assert!(Domain::Order.is_in_version(GeneratorVersion::V2));
+    /// ```
+    #[must_use]
+    pub fn is_in_version(self, version: GeneratorVersion) -> bool {
+        version > GeneratorVersion::V1 || Self::V1.contains(&self)
+    }
+
+    /// Choose a random domain using a specific version of the generator
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no domains to choose from, shouldn't happen!
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen<R: Rng + ?Sized>(rng: &mut R, version: GeneratorVersion) -> Self {
+        let domain = match version {
+            GeneratorVersion::V1 => *Self::V1.choose(rng).unwrap(),
+            GeneratorVersion::V2 | GeneratorVersion::V3 | GeneratorVersion::V4 => {
+                Self::iter().choose(rng).unwrap()
+            }
+        };
 
         metrics::counter!("domains", &[("domain", domain.to_string())]).increment(1);
 
@@ -206,13 +286,24 @@ impl Distribution<Domain> for Standard {
     }
 }
 
+impl Distribution<Domain> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Domain {
+        Domain::gen(rng, GeneratorVersion::LATEST)
+    }
+}
+
 /// Each world in the D&D multiverse has its own pantheons of deities, ranging
 /// in size from the teeming pantheons of the Forgotten Realms and Greyhawk to
 /// the more focused religions of Eberron and Dragonlance. Many of the nonhuman
 /// races worship the same gods on different worlds—Moradin, for example, is
 /// revered by dwarves of the Forgotten Realms, Greyhawk, and many other
 /// worlds.
+///
+/// Homebrew pantheons can also be loaded from data files with
+/// [`homebrew::load_dir`]. They are listed alongside the built-in ones, but
+/// are only generated from when asked for as a likely pantheon.
 #[derive(Copy, Clone, Debug, EnumIter, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "String")]
 pub enum Pantheon {
     /// Deities most commonly worshiped by Bugbears
     Bugbear,
@@ -285,7 +376,6 @@ pub enum Pantheon {
     /// world of the Forgotten Realms. At least thirty deities are widely known
     /// across the Realms, and many more are worshiped locally, by individual
     /// tribes, small cults, or certain sects of larger religious temples.
-    #[serde(rename = "Forgotten Realms")]
     ForgottenRealms,
     /// Deities most commonly worshipped by Giants
     Giant,
@@ -336,8 +426,73 @@ pub enum Pantheon {
     Norse,
     /// Deities most commonly worshipped by Orcs
     Orc,
+    /// A pantheon loaded from a data file, such as a campaign's own gods
+    #[strum(disabled)]
+    Homebrew {
+        /// Name of the pantheon
+        name: &'static str,
+    },
 }
 
+impl Pantheon {
+    /// Every pantheon: the built-in ones, followed by any homebrew pantheons
+    /// that have been loaded
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::iter().chain(homebrew::pantheons())
+    }
+}
+
+impl fmt::Display for Pantheon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForgottenRealms => write!(f, "Forgotten Realms"),
+            Self::Homebrew { name } => write!(f, "{name}"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+impl From<Pantheon> for String {
+    fn from(pantheon: Pantheon) -> Self {
+        pantheon.to_string()
+    }
+}
+
+impl FromStr for Pantheon {
+    type Err = ParsePantheonError;
+
+    /// Parse a pantheon by name, ignoring case. Includes homebrew pantheons
+    /// that have been loaded.
+    ///
+    /// ```
+    /// use deities::Pantheon;
+    ///
+    /// assert_eq!("Greek".parse(), Ok(Pantheon::Greek));
+    /// assert_eq!("forgotten realms".parse(), Ok(Pantheon::ForgottenRealms));
+    /// assert!("Unknown".parse::<Pantheon>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .find(|p| p.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParsePantheonError(s.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Pantheon {
+    /// Parsed by name, so that homebrew pantheons that have been loaded are
+    /// found as well
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Error caused by parsing an unknown pantheon
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("\"{0}\" isn't a known pantheon. Please choose one of the listed pantheons.")]
+pub struct ParsePantheonError(String);
+
 impl Pantheon {
     /// Get a list of deities that are part of this pantheon.
     /// Can filter by domain if desired
@@ -365,6 +520,7 @@ impl Pantheon {
             Self::Lizardfolk => lizardfolk::LIZARDFOLK,
             Self::Norse => norse::NORSE,
             Self::Orc => orc::ORC,
+            Self::Homebrew { name } => homebrew::deities(name),
         };
         if let Some(domain) = domain {
             Cow::Owned(
@@ -387,12 +543,14 @@ impl Pantheon {
     }
 
     /// Choose a pantheon, based on cultural pantheon influences as well as
-    /// character alignment
+    /// character alignment.
     ///
-    /// # Panics
+    /// Homebrew pantheons are only chosen if they are one of the likely
+    /// pantheons, so that loading them doesn't change seeded results.
     ///
-    /// Panics if a pantheon isn't chosen, shouldn't happen!
-    #[must_use]
+    /// # Errors
+    ///
+    /// Will error if no pantheon has a deity with the requested domain
     #[tracing::instrument(skip(rng))]
     pub fn gen<R: Rng + ?Sized>(
         rng: &mut R,
@@ -400,22 +558,29 @@ impl Pantheon {
         likely_pantheons: &[Self],
         attitude_influences: &[Attitude],
         morality_influences: &[Morality],
-    ) -> Self {
+    ) -> Result<Self, DeityError> {
         let pantheons = Pantheon::iter()
             .filter(|p| !p.deities(domain).is_empty())
             .collect::<Vec<_>>();
         let remaining_likely = likely_pantheons
             .iter()
-            .filter(|&p| pantheons.contains(p))
+            .filter(|p| !p.deities(domain).is_empty())
             .collect::<Vec<_>>();
+        if pantheons.is_empty() && remaining_likely.is_empty() {
+            return Err(DeityError::no_candidates(domain));
+        }
 
         // 10% chance you'll end up with an unlikely pantheon
-        if remaining_likely.is_empty() || Die::D10.roll(rng) == 10 {
-            *pantheons
+        if remaining_likely.is_empty() || (!pantheons.is_empty() && Die::D10.roll(rng) == 10) {
+            pantheons
                 .choose_weighted(rng, |p| p.weight(domain))
-                .unwrap()
+                .copied()
+                .map_err(|_| DeityError::no_candidates(domain))
         } else {
-            **remaining_likely.choose(rng).unwrap()
+            remaining_likely
+                .choose(rng)
+                .map(|&&p| p)
+                .ok_or(DeityError::no_candidates(domain))
         }
     }
 }
@@ -438,61 +603,286 @@ pub struct Deity {
     pub symbols: &'static [&'static str],
     /// Different titles the deity is also known by
     pub titles: &'static [&'static str],
+    /// Additional lore about the deity, if known
+    #[serde(flatten)]
+    pub lore: Lore,
 }
 
+/// Domains of the built-in deities as they were before the Order, Peace, and
+/// Twilight domains were added, keyed by their current domains
+static V1_DOMAINS: Lazy<HashMap<&'static [Domain], Vec<Domain>>> = Lazy::new(|| {
+    Pantheon::iter()
+        .flat_map(|p| p.deities(None).into_owned())
+        .map(|d| {
+            let domains = d.domains.iter().copied();
+            (
+                d.domains,
+                domains.filter(|d| Domain::V1.contains(d)).collect(),
+            )
+        })
+        .collect()
+});
+
 impl Deity {
-    /// Weight deity choice to more likely align to other alignment influences
-    fn weight(&self, attitude_influences: &[Attitude], morality_influences: &[Morality]) -> i32 {
-        self.alignment
-            .weight(attitude_influences, morality_influences)
+    /// Every deity, across all pantheons, including homebrew ones
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Pantheon::all().flat_map(|p| p.deities(None).into_owned())
+    }
+
+    /// This deity as a specific version of the generator describes them.
+    /// Built-in deities were only tagged with the Order, Peace, and Twilight
+    /// domains from V2 onward, so V1 leaves those domains out. Lore was added
+    /// in V3, so earlier versions leave it out.
+    ///
+    /// ```
+    /// use deities::{Deity, Domain, Pantheon};
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let tyr = Deity::named("Tyr")
+    ///     .find(|d| d.pantheon == Pantheon::ForgottenRealms)
+    ///     .unwrap();
+    /// assert!(tyr.domains.contains(&Domain::Order));
+    /// assert!(!tyr.lore.allies.is_empty());
+    ///
+    /// let tyr = tyr.for_version(GeneratorVersion::V1);
+    /// assert!(!tyr.domains.contains(&Domain::Order));
+    /// assert!(tyr.lore.allies.is_empty());
+    /// ```
+    #[must_use]
+    pub fn for_version(mut self, version: GeneratorVersion) -> Self {
+        if version == GeneratorVersion::V1 {
+            if let Some(domains) = V1_DOMAINS.get(self.domains) {
+                self.domains = domains;
+            }
+        }
+        if version < GeneratorVersion::V3 {
+            self.lore = Lore::NONE;
+        }
+        self
+    }
+
+    /// Every deity called by this name, ignoring case. Some deities, such as
+    /// Oghma or Tyr, are worshipped in more than one pantheon.
+    pub fn named(name: &str) -> impl Iterator<Item = Self> + '_ {
+        Self::iter().filter(move |d| d.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Whether this deity has a rival in one of these pantheons, other than
+    /// their own. Followers of those pantheons are unlikely to favor them.
+    ///
+    /// ```
+    /// use deities::{Deity, Pantheon};
+    ///
+    /// let gruumsh = Deity::named("Gruumsh").next().unwrap();
+    /// assert!(gruumsh.conflicts_with(&[Pantheon::Elven]));
+    /// assert!(!gruumsh.conflicts_with(&[Pantheon::Orc]));
+    /// ```
+    #[must_use]
+    pub fn conflicts_with(&self, pantheons: &[Pantheon]) -> bool {
+        self.lore
+            .rivals
+            .iter()
+            .any(|r| r.pantheon != self.pantheon && pantheons.contains(&r.pantheon))
+    }
+
+    /// Weight deity choice to more likely align to other alignment influences,
+    /// and less likely if the deity conflicts with the follower pantheons
+    fn weight(
+        &self,
+        follower_pantheons: &[Pantheon],
+        attitude_influences: &[Attitude],
+        morality_influences: &[Morality],
+    ) -> i32 {
+        let weight = self
+            .alignment
+            .weight(attitude_influences, morality_influences);
+        if self.conflicts_with(follower_pantheons) {
+            weight - UNLIKELY_PENALTY
+        } else {
+            weight
+        }
     }
 
     /// Choose a deity, based on cultural pantheon influences as well as
-    /// character alignment
+    /// character alignment. If a deity isn't required, the character may not
+    /// favor a deity at all.
+    ///
+    /// ```
+    /// use deities::{Deity, DeityOptions, Pantheon};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let deity = Deity::gen(
+    ///     &mut rng,
+    ///     &DeityOptions {
+    ///         likely_pantheons: &[Pantheon::Greek],
+    ///         required: true,
+    ///         ..DeityOptions::default()
+    ///     },
+    /// )?;
+    /// assert!(deity.is_some());
+    /// # Ok::<(), deities::DeityError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will error if no deity has the requested domain
     ///
     /// # Panics
     ///
-    /// Panics if a pantheon isn't chosen or is empty, shouldn't happen!
+    /// Will panic if weighting logic is wrong
     #[tracing::instrument(skip(rng))]
     pub fn gen<R: Rng + ?Sized>(
         rng: &mut R,
-        domain: Option<Domain>,
-        likely_pantheons: &[Pantheon],
-        attitude_influences: &[Attitude],
-        morality_influences: &[Morality],
-        required: bool,
-    ) -> Option<Self> {
+        options: &DeityOptions<'_>,
+    ) -> Result<Option<Self>, DeityError> {
+        let &DeityOptions {
+            domain,
+            likely_pantheons,
+            follower_pantheons,
+            attitude_influences,
+            morality_influences,
+            required,
+        } = options;
         let pantheon = Pantheon::gen(
             rng,
             domain,
             likely_pantheons,
             attitude_influences,
             morality_influences,
-        );
+        )?;
 
         let deity = *pantheon
             .deities(domain)
-            .choose_exp_weighted(rng, |d| d.weight(attitude_influences, morality_influences))
-            .unwrap();
+            .choose_exp_weighted(rng, |d| {
+                d.weight(follower_pantheons, attitude_influences, morality_influences)
+            })
+            .map_err(|_| DeityError::no_candidates(domain))?;
 
         if required {
-            Some(deity)
+            Ok(Some(deity))
         } else {
             // Weight deity choice based on how aligned it is with the character
-            [
+            Ok([
                 (
                     Some(deity),
-                    deity.weight(attitude_influences, morality_influences),
+                    deity.weight(follower_pantheons, attitude_influences, morality_influences),
                 ),
                 (None, 0),
             ]
             .choose_exp_weighted(rng, |i| i.1)
             .unwrap()
-            .0
+            .0)
         }
     }
 }
 
+/// Options for choosing a deity with [`Deity::gen`]. The defaults choose from
+/// every domain and pantheon, without any alignment influences, and allow no
+/// deity to be chosen at all.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeityOptions<'a> {
+    /// Only choose deities with this domain
+    pub domain: Option<Domain>,
+    /// Pantheons the character is culturally drawn to, and more likely to
+    /// choose from
+    pub likely_pantheons: &'a [Pantheon],
+    /// Pantheons followed by the character's people. Deities with a rival in
+    /// one of them are less likely to be chosen.
+    pub follower_pantheons: &'a [Pantheon],
+    /// Attitudes that make deities with a matching alignment more likely
+    pub attitude_influences: &'a [Attitude],
+    /// Moralities that make deities with a matching alignment more likely
+    pub morality_influences: &'a [Morality],
+    /// Whether the character has to favor a deity
+    pub required: bool,
+}
+
+/// Reference to another deity by name, within a given pantheon
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+pub struct DeityRef {
+    /// Pantheon the deity is a part of
+    pub pantheon: Pantheon,
+    /// Name of the deity within the pantheon
+    pub name: &'static str,
+}
+
+impl DeityRef {
+    /// Create a new reference to a deity
+    #[must_use]
+    pub const fn new(pantheon: Pantheon, name: &'static str) -> Self {
+        Self { pantheon, name }
+    }
+
+    /// The deity being referenced, if it exists
+    ///
+    /// ```
+    /// use deities::{DeityRef, Pantheon};
+    ///
+    /// let deity = DeityRef::new(Pantheon::Greek, "Zeus").deity().unwrap();
+    /// assert_eq!(deity.name, "Zeus");
+    /// assert!(DeityRef::new(Pantheon::Greek, "Thor").deity().is_none());
+    /// ```
+    #[must_use]
+    pub fn deity(&self) -> Option<Deity> {
+        self.pantheon
+            .deities(None)
+            .iter()
+            .find(|d| d.name == self.name)
+            .copied()
+    }
+}
+
+/// Lore about a deity beyond what is needed to recognize them. All of it is
+/// optional, and left out when serialized if unknown.
+#[derive(Copy, Clone, Debug, Default, Serialize)]
+pub struct Lore {
+    /// Deities this deity is allied with
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub allies: &'static [DeityRef],
+    /// Deities this deity is opposed to. Followers of a rival's pantheon are
+    /// unlikely to favor this deity.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub rivals: &'static [DeityRef],
+    /// Peoples and professions that typically worship this deity
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub worshippers: &'static [&'static str],
+    /// Days and festivals held sacred by followers of this deity
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub holy_days: &'static [&'static str],
+    /// Weapon favored by this deity and their clerics
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favored_weapon: Option<&'static str>,
+}
+
+impl Lore {
+    /// No lore is known about the deity
+    pub const NONE: Self = Self {
+        allies: &[],
+        rivals: &[],
+        worshippers: &[],
+        holy_days: &[],
+        favored_weapon: None,
+    };
+}
+
+/// Errors caused by generating a deity
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum DeityError {
+    /// No deities are available to choose from
+    #[error("There are no deities to choose from.")]
+    NoDeities,
+    /// No deities have the requested domain
+    #[error("There are no deities with the {0} domain. Please choose a different domain.")]
+    NoDeitiesWithDomain(Domain),
+}
+
+impl DeityError {
+    /// Error for when no deities are left after filtering by domain
+    fn no_candidates(domain: Option<Domain>) -> Self {
+        domain.map_or(Self::NoDeities, Self::NoDeitiesWithDomain)
+    }
+}
+
 /// Trait for aspects of a character that would influence deity choices
 pub trait Deities {
     /// Pantheons this would potentially lead to a character choosing from
@@ -504,6 +894,12 @@ pub trait Deities {
     fn deity_required(&self) -> bool {
         false
     }
+
+    /// Kinds of patrons this would make a character more likely to serve
+    /// instead of a deity
+    fn patron_kinds(&self) -> Cow<'_, [PatronKind]> {
+        Cow::Borrowed(&[])
+    }
 }
 
 impl AlignmentInfluences for Deity {
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, Domain, Lore, Pantheon};
 
 pub const LIZARDFOLK: &[Deity] = &[Deity {
     name: "Semuanya",
@@ -9,4 +9,5 @@ pub const LIZARDFOLK: &[Deity] = &[Deity {
     pantheon: Pantheon::Lizardfolk,
     symbols: &["Egg"],
     titles: &["deity of survival"],
+    lore: Lore::NONE,
 }];
@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const NORSE: &[Deity] = &[
     Deity {
@@ -10,6 +10,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Watching blue eye"],
         titles: &["god of knowledge and war"],
+        lore: Lore {
+            worshippers: &["Rulers", "Skalds", "Warriors"],
+            favored_weapon: Some("Spear"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Aegir",
@@ -18,6 +23,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Rough ocean waves"],
         titles: &["god of the sea and storms"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Balder",
@@ -26,14 +32,16 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Gem-encrusted silver chalice"],
         titles: &["god of beauty and poetry"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Forseti",
         alignment: Alignment::NEUTRAL,
-        domains: &[Domain::Light],
+        domains: &[Domain::Light, Domain::Order],
         pantheon: Pantheon::Norse,
         symbols: &["Head of a bearded man"],
         titles: &["god of justice and law"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Frey",
@@ -42,6 +50,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Ice-blue greatsword"],
         titles: &["god of fertility and the sun"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Norse, "Freya")],
+            worshippers: &["Farmers", "Sailors"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Freya",
@@ -50,6 +63,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Falcon"],
         titles: &["goddess of fertility and love"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Norse, "Frey")],
+            worshippers: &["Lovers", "Seers"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Frigga",
@@ -58,6 +76,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Cat"],
         titles: &["goddess of birth and fertility"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Heimdall",
@@ -66,6 +85,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Curling musical horn"],
         titles: &["god of watchfulness and loyalty"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Norse, "Loki")],
+            worshippers: &["Guards", "Sentries"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Hel",
@@ -74,6 +98,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Woman's face, rotting on one side"],
         titles: &["goddess of the underworld"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Hermod",
@@ -82,6 +107,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Winged scroll"],
         titles: &["god of luck"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Loki",
@@ -90,6 +116,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Flame"],
         titles: &["god of thieves and trickery"],
+        lore: Lore {
+            rivals: &[DeityRef::new(Pantheon::Norse, "Heimdall")],
+            worshippers: &["Tricksters", "Thieves"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Njord",
@@ -98,6 +129,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Gold coin"],
         titles: &["god of sea and wind"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Odur",
@@ -106,6 +138,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Solar disk"],
         titles: &["god of light and the sun"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Sif",
@@ -114,6 +147,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Upraised sword"],
         titles: &["goddess of war"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Skadi",
@@ -122,6 +156,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Mountain peak"],
         titles: &["god of earth and mountains"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Surtur",
@@ -130,6 +165,7 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Flaming sword"],
         titles: &["god of fire giants and war"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Thor",
@@ -138,6 +174,11 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Hammer"],
         titles: &["god of storms and thunder"],
+        lore: Lore {
+            worshippers: &["Farmers", "Warriors", "Commoners"],
+            favored_weapon: Some("Warhammer"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Thrym",
@@ -146,14 +187,16 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["White double-bladed axe"],
         titles: &["god of frost giants and cold"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Tyr",
         alignment: Alignment::LAWFUL_NEUTRAL,
-        domains: &[Domain::Knowledge, Domain::War],
+        domains: &[Domain::Knowledge, Domain::Order, Domain::War],
         pantheon: Pantheon::Norse,
         symbols: &["Sword"],
         titles: &["god of courage and strategy"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Uller",
@@ -162,5 +205,6 @@ pub const NORSE: &[Deity] = &[
         pantheon: Pantheon::Norse,
         symbols: &["Longbow"],
         titles: &["god of hunting and winter"],
+        lore: Lore::NONE,
     },
 ];
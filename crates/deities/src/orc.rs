@@ -1,6 +1,6 @@
 use alignments::Alignment;
 
-use crate::{Deity, Domain, Pantheon};
+use crate::{Deity, DeityRef, Domain, Lore, Pantheon};
 
 pub const ORC: &[Deity] = &[
     Deity {
@@ -10,6 +10,11 @@ pub const ORC: &[Deity] = &[
         pantheon: Pantheon::Orc,
         symbols: &["Broken thigh bone"],
         titles: &["god of pure, brute strength", "son of Gruumsh"],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Orc, "Gruumsh")],
+            worshippers: &["Orc warriors"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Gruumsh",
@@ -22,6 +27,15 @@ pub const ORC: &[Deity] = &[
             "god of conquest, strength, and survival",
             "One-Eye",
         ],
+        lore: Lore {
+            rivals: &[
+                DeityRef::new(Pantheon::Elven, "Corellon Larathian"),
+                DeityRef::new(Pantheon::Goblin, "Maglubiyet"),
+            ],
+            worshippers: &["Orcs", "Half-orcs", "Raiders"],
+            favored_weapon: Some("Spear"),
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Ilneval",
@@ -30,6 +44,7 @@ pub const ORC: &[Deity] = &[
         pantheon: Pantheon::Orc,
         symbols: &["Upright blood-spattered sword"],
         titles: &["god of strategy and hordes", "War Master"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Luthic",
@@ -41,6 +56,11 @@ pub const ORC: &[Deity] = &[
             "mother-goddess of fertility and healding",
             "goddess of fecundity, caverns, and witchery",
         ],
+        lore: Lore {
+            allies: &[DeityRef::new(Pantheon::Orc, "Gruumsh")],
+            worshippers: &["Orc mothers", "Orc healers"],
+            ..Lore::NONE
+        },
     },
     Deity {
         name: "Shargaas",
@@ -49,6 +69,7 @@ pub const ORC: &[Deity] = &[
         pantheon: Pantheon::Orc,
         symbols: &["Red crescent moon with a skull between the moon's horns"],
         titles: &["god of darkness, night, and stealth", "the Night Lord"],
+        lore: Lore::NONE,
     },
     Deity {
         name: "Yurtrus",
@@ -62,5 +83,6 @@ pub const ORC: &[Deity] = &[
             "the White-Handed",
             "Lord of Maggots",
         ],
+        lore: Lore::NONE,
     },
 ];
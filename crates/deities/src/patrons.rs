@@ -0,0 +1,270 @@
+use std::borrow::Cow;
+
+use alignments::{Alignment, AlignmentInfluences, Attitude, Morality};
+use dice::Die;
+use rand::Rng;
+use rand_utils::SliceExpRandom;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+/// Kinds of beings and beliefs a character might devote themselves to,
+/// rather than a deity.
+#[derive(Copy, Clone, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
+pub enum PatronKind {
+    /// Lords and ladies of the fey, creatures of legend who guard secrets
+    /// that were old before the mortal races were born. Their motivations
+    /// are often inscrutable, and sometimes whimsical.
+    Archfey,
+    /// Beings of the Lower Planes, such as demon lords and archdevils, whose
+    /// aims are evil even if their pacts seem generous.
+    Fiend,
+    /// Entities whose nature is utterly foreign to the fabric of reality.
+    /// They might come from the Far Realm, or be ancient things imprisoned
+    /// or forgotten, and may be unaware of their servants.
+    #[serde(rename = "Great Old One")]
+    #[strum(serialize = "Great Old One")]
+    GreatOldOne,
+    /// Abstract beliefs about the nature of the multiverse, followed with
+    /// the same devotion others give to a god.
+    Philosophy,
+}
+
+/// A patron or philosophy a character could serve instead of a deity. Like
+/// deities, their alignment influences the alignment of their followers.
+#[derive(Copy, Clone, Debug, Serialize)]
+pub struct Patron {
+    /// Name the patron is called by
+    pub name: &'static str,
+    /// Alignment of the patron, or of the followers of a philosophy
+    pub alignment: Alignment,
+    /// What kind of patron this is
+    pub kind: PatronKind,
+    /// Different titles the patron is also known by
+    pub titles: &'static [&'static str],
+}
+
+impl Patron {
+    /// Every available patron and philosophy
+    pub fn iter() -> impl Iterator<Item = Self> {
+        PATRONS.iter().copied()
+    }
+
+    /// Choose a patron, more likely from the kinds of patrons a character's
+    /// culture is drawn to, and weighted by character alignment.
+    ///
+    /// ```
+    /// use deities::{Patron, PatronKind};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let patron = Patron::gen(&mut rng, &[PatronKind::Archfey], &[], &[]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will panic if weighting logic is wrong
+    #[must_use]
+    #[tracing::instrument(skip(rng))]
+    pub fn gen<R: Rng + ?Sized>(
+        rng: &mut R,
+        likely_kinds: &[PatronKind],
+        attitude_influences: &[Attitude],
+        morality_influences: &[Morality],
+    ) -> Self {
+        // 10% chance you'll end up with an unlikely kind of patron
+        let patrons = if likely_kinds.is_empty() || Die::D10.roll(rng) == 10 {
+            PATRONS.iter().collect::<Vec<_>>()
+        } else {
+            PATRONS
+                .iter()
+                .filter(|p| likely_kinds.contains(&p.kind))
+                .collect()
+        };
+
+        **patrons
+            .choose_exp_weighted(rng, |p| {
+                p.alignment.weight(attitude_influences, morality_influences)
+            })
+            .unwrap()
+    }
+}
+
+impl AlignmentInfluences for Patron {
+    fn attitude(&self) -> Cow<'_, [Attitude]> {
+        self.alignment.attitude()
+    }
+
+    fn morality(&self) -> Cow<'_, [Morality]> {
+        self.alignment.morality()
+    }
+}
+
+const PATRONS: &[Patron] = &[
+    Patron {
+        name: "Baba Yaga",
+        alignment: Alignment::NEUTRAL_EVIL,
+        kind: PatronKind::Archfey,
+        titles: &["Mother of All Witches", "Grandmother of the Dancing Hut"],
+    },
+    Patron {
+        name: "Hyrsam",
+        alignment: Alignment::CHAOTIC_NEUTRAL,
+        kind: PatronKind::Archfey,
+        titles: &["the Prince of Fools"],
+    },
+    Patron {
+        name: "Oberon",
+        alignment: Alignment::CHAOTIC_GOOD,
+        kind: PatronKind::Archfey,
+        titles: &["the Green Lord"],
+    },
+    Patron {
+        name: "The Prince of Frost",
+        alignment: Alignment::NEUTRAL_EVIL,
+        kind: PatronKind::Archfey,
+        titles: &["the Lord of the Gloaming Court"],
+    },
+    Patron {
+        name: "The Queen of Air and Darkness",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::Archfey,
+        titles: &["ruler of the Gloaming Court"],
+    },
+    Patron {
+        name: "Titania",
+        alignment: Alignment::CHAOTIC_GOOD,
+        kind: PatronKind::Archfey,
+        titles: &["the Summer Queen", "ruler of the Seelie Court"],
+    },
+    Patron {
+        name: "Asmodeus",
+        alignment: Alignment::LAWFUL_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["Lord of the Ninth", "the Overlord of Nessus"],
+    },
+    Patron {
+        name: "Demogorgon",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["the Prince of Demons"],
+    },
+    Patron {
+        name: "Dispater",
+        alignment: Alignment::LAWFUL_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["the Iron Duke", "Lord of Dis"],
+    },
+    Patron {
+        name: "Fierna",
+        alignment: Alignment::LAWFUL_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["Lord of Phlegethos"],
+    },
+    Patron {
+        name: "Graz'zt",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["the Dark Prince"],
+    },
+    Patron {
+        name: "Mephistopheles",
+        alignment: Alignment::LAWFUL_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["Lord of Cania", "the Lord of Hellfire"],
+    },
+    Patron {
+        name: "Orcus",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["the Prince of Undeath"],
+    },
+    Patron {
+        name: "Yeenoghu",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::Fiend,
+        titles: &["the Gnoll Lord", "the Beast of Butchery"],
+    },
+    Patron {
+        name: "Acamar",
+        alignment: Alignment::NEUTRAL,
+        kind: PatronKind::GreatOldOne,
+        titles: &["the Nameless Void", "the Star Spawn"],
+    },
+    Patron {
+        name: "Dendar",
+        alignment: Alignment::NEUTRAL_EVIL,
+        kind: PatronKind::GreatOldOne,
+        titles: &["the Night Serpent", "the Eater of the World"],
+    },
+    Patron {
+        name: "Hadar",
+        alignment: Alignment::NEUTRAL_EVIL,
+        kind: PatronKind::GreatOldOne,
+        titles: &["the Dark Hunger"],
+    },
+    Patron {
+        name: "Tharizdun",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::GreatOldOne,
+        titles: &["the Chained God", "the Elder Elemental Eye"],
+    },
+    Patron {
+        name: "Zargon",
+        alignment: Alignment::CHAOTIC_EVIL,
+        kind: PatronKind::GreatOldOne,
+        titles: &["the Returner"],
+    },
+    Patron {
+        name: "The Athar",
+        alignment: Alignment::NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Lost", "the Defiers"],
+    },
+    Patron {
+        name: "The Believers of the Source",
+        alignment: Alignment::NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Godsmen"],
+    },
+    Patron {
+        name: "The Bleak Cabal",
+        alignment: Alignment::CHAOTIC_NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Bleakers", "the Madmen"],
+    },
+    Patron {
+        name: "The Doomguard",
+        alignment: Alignment::CHAOTIC_NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Sinkers"],
+    },
+    Patron {
+        name: "The Fraternity of Order",
+        alignment: Alignment::LAWFUL_NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Guvners"],
+    },
+    Patron {
+        name: "The Harmonium",
+        alignment: Alignment::LAWFUL_GOOD,
+        kind: PatronKind::Philosophy,
+        titles: &["the Hardheads"],
+    },
+    Patron {
+        name: "The Mercykillers",
+        alignment: Alignment::LAWFUL_NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Red Death"],
+    },
+    Patron {
+        name: "The Society of Sensation",
+        alignment: Alignment::CHAOTIC_NEUTRAL,
+        kind: PatronKind::Philosophy,
+        titles: &["the Sensates"],
+    },
+    Patron {
+        name: "The Transcendent Order",
+        alignment: Alignment::CHAOTIC_GOOD,
+        kind: PatronKind::Philosophy,
+        titles: &["the Ciphers"],
+    },
+];
@@ -0,0 +1,298 @@
+//! Integration tests for homebrew pantheons. Kept separate from the other
+//! tests, since loaded pantheons are added to the lists of all pantheons and
+//! deities.
+#![warn(
+    clippy::pedantic,
+    future_incompatible,
+    missing_debug_implementations,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    rust_2021_compatibility,
+    unused
+)]
+
+use deities::{
+    homebrew::{self, HomebrewError, PantheonData},
+    Deity, DeityOptions, Domain, Pantheon,
+};
+use once_cell::sync::Lazy;
+use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
+
+/// Pantheons loaded from the test data directory, loaded once for all tests
+static LOADED: Lazy<Vec<Pantheon>> = Lazy::new(|| {
+    homebrew::load_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/homebrew")).unwrap()
+});
+
+fn pantheon(json: &str) -> PantheonData {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn loads_pantheons_in_file_order() {
+    assert_eq!(
+        LOADED.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        ["The Shattered Court", "Wild Hearth"]
+    );
+}
+
+#[test]
+fn homebrew_pantheons_are_listed_after_built_in_ones() {
+    let all = Pantheon::all().collect::<Vec<_>>();
+
+    assert_eq!(all[0], Pantheon::Bugbear);
+    assert!(LOADED.iter().all(|p| all.contains(p)));
+}
+
+#[test]
+fn homebrew_deities_match_their_pantheon() {
+    for pantheon in &*LOADED {
+        let deities = pantheon.deities(None);
+        assert!(!deities.is_empty());
+        for deity in &*deities {
+            assert_eq!(deity.pantheon, *pantheon);
+        }
+    }
+}
+
+#[test]
+fn homebrew_pantheons_can_be_parsed() {
+    let pantheon = LOADED[0];
+
+    assert_eq!("the shattered court".parse(), Ok(pantheon));
+    assert_eq!(
+        serde_json::from_str::<Pantheon>("\"The Shattered Court\"").unwrap(),
+        pantheon
+    );
+    assert_eq!(
+        serde_json::to_string(&pantheon).unwrap(),
+        "\"The Shattered Court\""
+    );
+}
+
+#[test]
+fn homebrew_deities_can_be_found() {
+    Lazy::force(&LOADED);
+
+    let deity = Deity::named("velis").next().unwrap();
+    assert_eq!(deity.domains, [Domain::Order, Domain::Light]);
+    assert_eq!(deity.titles, ["the Last Judge"]);
+}
+
+#[test]
+fn homebrew_lore_is_loaded() {
+    Lazy::force(&LOADED);
+    let velis = Deity::named("Velis").next().unwrap();
+
+    assert_eq!(velis.lore.worshippers, ["Magistrates"]);
+    assert_eq!(velis.lore.favored_weapon, Some("Longsword"));
+    assert_eq!(velis.lore.rivals[0].deity().unwrap().name, "Morrow");
+    assert_eq!(
+        velis.lore.allies[0].deity().unwrap().pantheon,
+        Pantheon::ForgottenRealms
+    );
+}
+
+#[test]
+fn homebrew_deities_are_generated() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let likely = [LOADED[1]];
+
+    let hearth = (0..100)
+        .filter_map(|_| {
+            Deity::gen(
+                &mut rng,
+                &DeityOptions {
+                    domain: Some(Domain::Peace),
+                    likely_pantheons: &likely,
+                    required: true,
+                    ..DeityOptions::default()
+                },
+            )
+            .unwrap()
+        })
+        .filter(|d| d.name == "Ama")
+        .count();
+    assert!(hearth > 50);
+}
+
+#[test]
+fn homebrew_pantheons_are_only_generated_if_likely() {
+    Lazy::force(&LOADED);
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for _ in 0..1000 {
+        let deity = Deity::gen(
+            &mut rng,
+            &DeityOptions {
+                required: true,
+                ..DeityOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap();
+        assert!(!LOADED.contains(&deity.pantheon));
+    }
+}
+
+#[test]
+fn seeded_deities_are_unchanged_by_homebrew() {
+    Lazy::force(&LOADED);
+
+    // Same output as the golden files of the main integration tests
+    assert_golden_versions(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"),
+        &[
+            GeneratorVersion::V1,
+            GeneratorVersion::V2,
+            GeneratorVersion::V3,
+        ],
+        |version| {
+            (0..10)
+                .map(|seed| {
+                    let mut rng = version.rng_from_seed(seed);
+                    (
+                        Domain::gen(&mut rng, version),
+                        Deity::gen(
+                            &mut rng,
+                            &DeityOptions {
+                                required: true,
+                                ..DeityOptions::default()
+                            },
+                        )
+                        .unwrap()
+                        .map(|d| d.for_version(version)),
+                    )
+                })
+                .collect::<Vec<_>>()
+        },
+    );
+}
+
+#[test]
+fn references_ignore_case() {
+    let pantheon = pantheon(
+        r#"{"name": "Lowercase", "deities": [
+            {"name": "Ash", "alignment": "N", "allies": [{"name": "ember"}, {"pantheon": "greek", "name": "zeus"}]},
+            {"name": "Ember", "alignment": "N"}
+        ]}"#,
+    )
+    .load()
+    .unwrap();
+
+    let allies = pantheon.deities(None)[0].lore.allies;
+    assert_eq!(allies[0].name, "Ember");
+    assert_eq!(allies[1].deity().unwrap().name, "Zeus");
+}
+
+#[test]
+fn references_to_other_homebrew_pantheons() {
+    pantheon(r#"{"name": "Elder Hall", "deities": [{"name": "Old One", "alignment": "LN"}]}"#)
+        .load()
+        .unwrap();
+    let younger = pantheon(
+        r#"{"name": "Younger Hall", "deities": [
+            {"name": "New One", "alignment": "CN", "rivals": [{"pantheon": "Elder Hall", "name": "old one"}]}
+        ]}"#,
+    )
+    .load()
+    .unwrap();
+
+    let rival = younger.deities(None)[0].lore.rivals[0].deity().unwrap();
+    assert_eq!(rival.name, "Old One");
+}
+
+#[test]
+fn duplicate_pantheons_are_rejected() {
+    Lazy::force(&LOADED);
+
+    for name in ["Greek", "forgotten realms", "Wild Hearth"] {
+        let error = pantheon(&format!(
+            r#"{{"name": "{name}", "deities": [{{"name": "A", "alignment": "N"}}]}}"#
+        ))
+        .load()
+        .unwrap_err();
+        assert!(
+            matches!(error, HomebrewError::DuplicatePantheon(_)),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn invalid_pantheons_are_rejected() {
+    assert!(matches!(
+        pantheon(r#"{"name": " ", "deities": [{"name": "A", "alignment": "N"}]}"#).load(),
+        Err(HomebrewError::MissingPantheonName)
+    ));
+    assert!(matches!(
+        pantheon(r#"{"name": "Empty", "deities": []}"#).load(),
+        Err(HomebrewError::NoDeities(_))
+    ));
+    assert!(matches!(
+        pantheon(r#"{"name": "Nameless", "deities": [{"name": "", "alignment": "N"}]}"#).load(),
+        Err(HomebrewError::MissingDeityName(_))
+    ));
+    assert!(matches!(
+        pantheon(
+            r#"{"name": "Twins", "deities": [{"name": "A", "alignment": "N"}, {"name": "a", "alignment": "CG"}]}"#
+        )
+        .load(),
+        Err(HomebrewError::DuplicateDeity { .. })
+    ));
+    assert!(matches!(
+        pantheon(
+            r#"{"name": "Lonely", "deities": [{"name": "A", "alignment": "N", "allies": [{"name": "B"}]}]}"#
+        )
+        .load(),
+        Err(HomebrewError::UnknownDeity { .. })
+    ));
+    assert!(matches!(
+        pantheon(
+            r#"{"name": "Feuding", "deities": [{"name": "A", "alignment": "N", "rivals": [{"pantheon": "Greek", "name": "Odin"}]}]}"#
+        )
+        .load(),
+        Err(HomebrewError::UnknownDeity { .. })
+    ));
+    assert!(serde_json::from_str::<PantheonData>(
+        r#"{"name": "Bad", "deities": [{"name": "A", "alignment": "Sideways"}]}"#
+    )
+    .is_err());
+    assert!(serde_json::from_str::<PantheonData>(
+        r#"{"name": "Bad", "deities": [{"name": "A", "alignment": "N", "domains": ["Cheese"]}]}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn unreadable_files_are_rejected() {
+    assert!(matches!(
+        PantheonData::from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/homebrew/notes.txt"
+        )),
+        Err(HomebrewError::UnsupportedFile(_))
+    ));
+    assert!(matches!(
+        homebrew::load_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/missing")),
+        Err(HomebrewError::Io { .. })
+    ));
+}
+
+#[test]
+fn nothing_is_loaded_if_any_file_is_invalid() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/homebrew_invalid");
+    assert!(matches!(
+        homebrew::load_dir(dir),
+        Err(HomebrewError::NoDeities(_))
+    ));
+    assert!("Half Loaded".parse::<Pantheon>().is_err());
+
+    // The valid file can still be loaded on its own
+    let pantheon = PantheonData::from_file(format!("{dir}/first.json"))
+        .unwrap()
+        .load()
+        .unwrap();
+    assert_eq!(pantheon.to_string(), "Half Loaded");
+}
@@ -1,3 +1,4 @@
+//! Integration tests for the `deities` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -10,8 +11,10 @@
     unused
 )]
 
-use deities::{Deity, Domain, Pantheon};
+use alignments::Morality;
+use deities::{Deity, DeityOptions, Domain, Pantheon, Patron, PatronKind};
 use rand::Rng;
+use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
 use strum::IntoEnumIterator;
 
 #[test]
@@ -23,16 +26,52 @@ fn all_deities_match_their_pantheon() {
     }
 }
 
+#[test]
+fn all_deities_are_iterated() {
+    assert_eq!(
+        Deity::iter().count(),
+        Pantheon::iter()
+            .map(|p| p.deities(None).len())
+            .sum::<usize>()
+    );
+}
+
+#[test]
+fn deities_can_be_found_by_name() {
+    let deities = Deity::named("tyr").collect::<Vec<_>>();
+    assert!(deities.len() > 1);
+    assert!(deities.iter().all(|d| d.name == "Tyr"));
+
+    assert_eq!(Deity::named("Nobody").count(), 0);
+}
+
+#[test]
+fn every_domain_has_a_deity() {
+    for domain in Domain::iter() {
+        assert!(
+            Pantheon::iter().any(|p| !p.deities(Some(domain)).is_empty()),
+            "{domain}"
+        );
+    }
+}
+
 #[test]
 fn gen_pantheon() {
     let mut rng = rand_utils::rng_from_entropy();
-    let _ = Pantheon::gen(&mut rng, None, &[], &[], &[]);
+    assert!(Pantheon::gen(&mut rng, None, &[], &[], &[]).is_ok());
 }
 
 #[test]
 fn gen_deity_if_required() {
     let mut rng = rand_utils::rng_from_entropy();
-    let deity = Deity::gen(&mut rng, None, &[], &[], &[], true);
+    let deity = Deity::gen(
+        &mut rng,
+        &DeityOptions {
+            required: true,
+            ..DeityOptions::default()
+        },
+    )
+    .unwrap();
     assert!(deity.is_some());
 }
 
@@ -41,7 +80,7 @@ fn dont_gen_deity_if_not_required() {
     let mut rng = rand_utils::rng_from_entropy();
     let mut deity = None;
     for _ in 0..1000 {
-        deity = Deity::gen(&mut rng, None, &[], &[], &[], false);
+        deity = Deity::gen(&mut rng, &DeityOptions::default()).unwrap();
         if deity.is_none() {
             break;
         }
@@ -49,13 +88,142 @@ fn dont_gen_deity_if_not_required() {
     assert!(deity.is_none());
 }
 
+#[test]
+fn likely_pantheons_are_favored() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let greek = (0..100)
+        .filter_map(|_| {
+            Deity::gen(
+                &mut rng,
+                &DeityOptions {
+                    likely_pantheons: &[Pantheon::Greek],
+                    required: true,
+                    ..DeityOptions::default()
+                },
+            )
+            .unwrap()
+        })
+        .filter(|d| d.pantheon == Pantheon::Greek)
+        .count();
+
+    assert!(greek > 50);
+}
+
 #[test]
 fn domain_matches_if_selected() {
     let mut rng = rand_utils::rng_from_entropy();
     for _ in 0..10 {
         let domain = rng.gen::<Domain>();
-        let deity = Deity::gen(&mut rng, Some(domain), &[], &[], &[], true).unwrap();
+        let deity = Deity::gen(
+            &mut rng,
+            &DeityOptions {
+                domain: Some(domain),
+                required: true,
+                ..DeityOptions::default()
+            },
+        )
+        .unwrap()
+        .unwrap();
 
         assert!(deity.domains.contains(&domain));
     }
 }
+
+/// Versions that changed the output of [`seeded_deities_are_stable`]. V2 added
+/// new domains, and V3 added deity lore.
+const DEITY_CHANGES: &[GeneratorVersion] = &[
+    GeneratorVersion::V1,
+    GeneratorVersion::V2,
+    GeneratorVersion::V3,
+];
+
+#[test]
+fn seeded_deities_are_stable() {
+    assert_golden_versions(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"),
+        DEITY_CHANGES,
+        |version| {
+            (0..10)
+                .map(|seed| {
+                    let mut rng = version.rng_from_seed(seed);
+                    (
+                        Domain::gen(&mut rng, version),
+                        Deity::gen(
+                            &mut rng,
+                            &DeityOptions {
+                                required: true,
+                                ..DeityOptions::default()
+                            },
+                        )
+                        .unwrap()
+                        .map(|d| d.for_version(version)),
+                    )
+                })
+                .collect::<Vec<_>>()
+        },
+    );
+}
+
+#[test]
+fn allies_and_rivals_exist() {
+    for deity in Deity::iter() {
+        for other in deity.lore.allies.iter().chain(deity.lore.rivals) {
+            assert!(other.deity().is_some(), "{}: {other:?}", deity.name);
+        }
+    }
+}
+
+#[test]
+fn rivals_of_follower_pantheons_are_less_likely() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let count = |rng: &mut _, follower_pantheons: &[Pantheon]| {
+        (0..1000)
+            .filter_map(|_| {
+                Deity::gen(
+                    rng,
+                    &DeityOptions {
+                        likely_pantheons: &[Pantheon::Orc],
+                        follower_pantheons,
+                        required: true,
+                        ..DeityOptions::default()
+                    },
+                )
+                .unwrap()
+            })
+            .filter(|d| d.name == "Gruumsh")
+            .count()
+    };
+
+    assert!(count(&mut rng, &[Pantheon::Elven]) < count(&mut rng, &[]));
+}
+
+#[test]
+fn every_patron_kind_has_a_patron() {
+    for kind in PatronKind::iter() {
+        assert!(Patron::iter().any(|p| p.kind == kind), "{kind}");
+    }
+}
+
+#[test]
+fn likely_patron_kinds_are_favored() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let archfey = (0..100)
+        .map(|_| Patron::gen(&mut rng, &[PatronKind::Archfey], &[], &[]))
+        .filter(|p| p.kind == PatronKind::Archfey)
+        .count();
+
+    assert!(archfey > 50);
+}
+
+#[test]
+fn patrons_are_weighted_by_alignment() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let mut fiends = |morality| {
+        (0..1000)
+            .map(|_| Patron::gen(&mut rng, &[], &[], &[morality, morality]))
+            .filter(|p| p.kind == PatronKind::Fiend)
+            .count()
+    };
+
+    assert!(fiends(Morality::Evil) > fiends(Morality::Good));
+}
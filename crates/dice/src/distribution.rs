@@ -0,0 +1,454 @@
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::{Expression, Roll, Selector, Sign, Term, MAX_EXPLOSIONS};
+
+/// Rough upper bound on the number of steps needed to calculate a
+/// distribution, to keep huge rolls from requesting an unreasonable amount of
+/// work.
+const MAX_WORK: usize = 100_000_000;
+
+/// Exact probability of every possible result of a roll or expression,
+/// calculated without rolling any dice.
+///
+/// ```
+/// use dice::{Die, Roll};
+///
+/// let distribution = Roll::new(2, Die::D6).distribution()?;
+///
+/// assert!((distribution.probability(7) - 6.0 / 36.0).abs() < 1e-9);
+/// assert_eq!(distribution.mean(), 7.0);
+/// assert_eq!(distribution.percentile(50.0), 7);
+/// # Ok::<(), dice::DistributionError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbabilityDistribution {
+    /// Lowest value with a probability, which may be zero
+    min: i32,
+    /// Probability of each value, starting at `min`
+    probabilities: Vec<f64>,
+}
+
+impl ProbabilityDistribution {
+    /// A single value that will always be the result
+    fn constant(value: i32) -> Self {
+        Self {
+            min: value,
+            probabilities: vec![1.0],
+        }
+    }
+
+    /// Every value in the inclusive range is equally likely
+    fn uniform(min: i32, max: i32) -> Self {
+        Self {
+            min,
+            probabilities: vec![1.0 / f64::from(max - min + 1); Self::index(max - min) + 1],
+        }
+    }
+
+    /// Build a distribution from values and their probabilities. Values that
+    /// show up more than once have their probabilities added together.
+    fn from_outcomes(outcomes: impl IntoIterator<Item = (i32, f64)>) -> Self {
+        let outcomes =
+            outcomes
+                .into_iter()
+                .fold(BTreeMap::new(), |mut outcomes, (value, probability)| {
+                    *outcomes.entry(value).or_insert(0.0) += probability;
+                    outcomes
+                });
+        let (Some((&min, _)), Some((&max, _))) =
+            (outcomes.first_key_value(), outcomes.last_key_value())
+        else {
+            return Self::constant(0);
+        };
+
+        let mut probabilities = vec![0.0; Self::index(max - min) + 1];
+        for (value, probability) in outcomes {
+            probabilities[Self::index(value - min)] = probability;
+        }
+        Self { min, probabilities }
+    }
+
+    /// Convert a non-negative offset from `min` into an index
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the offset is negative, since that would mean a value
+    /// below the minimum
+    fn index(offset: i32) -> usize {
+        usize::try_from(offset).expect("offset from the minimum should be non-negative")
+    }
+
+    /// Distribution of adding a result from each distribution together
+    fn convolve(&self, other: &Self) -> Self {
+        let mut probabilities = vec![0.0; self.probabilities.len() + other.probabilities.len() - 1];
+        for (i, a) in self.probabilities.iter().enumerate() {
+            for (j, b) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += a * b;
+            }
+        }
+        Self {
+            min: self.min + other.min,
+            probabilities,
+        }
+    }
+
+    /// Distribution of the result multiplied by -1
+    fn negate(&self) -> Self {
+        Self {
+            min: -(self.min + i32::try_from(self.probabilities.len()).unwrap_or(i32::MAX) - 1),
+            probabilities: self.probabilities.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Lowest possible result
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let distribution = "2d6-1".parse::<Expression>()?.distribution()?;
+    /// assert_eq!(distribution.min(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn min(&self) -> i32 {
+        self.probabilities()
+            .next()
+            .map_or(self.min, |(value, _)| value)
+    }
+
+    /// Highest possible result
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let distribution = "2d6-1".parse::<Expression>()?.distribution()?;
+    /// assert_eq!(distribution.max(), 11);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn max(&self) -> i32 {
+        self.probabilities()
+            .last()
+            .map_or(self.min, |(value, _)| value)
+    }
+
+    /// Probability of rolling exactly the given value
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(1, Die::D20).distribution()?;
+    /// assert_eq!(distribution.probability(20), 0.05);
+    /// assert_eq!(distribution.probability(21), 0.0);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    #[must_use]
+    pub fn probability(&self, value: i32) -> f64 {
+        usize::try_from(value - self.min)
+            .ok()
+            .and_then(|i| self.probabilities.get(i))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Every possible result along with its probability, from lowest to
+    /// highest. Results that can't be rolled are skipped.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(1, Die::Fudge).distribution()?;
+    /// let values = distribution.probabilities().map(|(v, _)| v).collect::<Vec<_>>();
+    /// assert_eq!(values, vec![-1, 0, 1]);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    pub fn probabilities(&self) -> impl Iterator<Item = (i32, f64)> + '_ {
+        (self.min..)
+            .zip(self.probabilities.iter().copied())
+            .filter(|&(_, probability)| probability > 0.0)
+    }
+
+    /// Average result
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(4, Die::D6).drop_lowest(1).distribution()?;
+    /// assert!((distribution.mean() - 12.2446).abs() < 0.0001);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        self.probabilities()
+            .map(|(value, probability)| f64::from(value) * probability)
+            .sum()
+    }
+
+    /// How spread out the results are from the mean
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(1, Die::D6).distribution()?;
+    /// assert!((distribution.variance() - 35.0 / 12.0).abs() < 1e-9);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.probabilities()
+            .map(|(value, probability)| (f64::from(value) - mean).powi(2) * probability)
+            .sum()
+    }
+
+    /// Standard deviation of the results, the square root of the variance
+    #[must_use]
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Lowest result that at least the given percent of rolls are less than
+    /// or equal to. `percentile(50.0)` is the median.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(1, Die::D20).distribution()?;
+    /// assert_eq!(distribution.percentile(25.0), 5);
+    /// assert_eq!(distribution.percentile(100.0), 20);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    #[must_use]
+    pub fn percentile(&self, percent: f64) -> i32 {
+        // Leave some room for floating point error when adding up
+        let target = percent / 100.0 - 1e-9;
+        let mut cumulative = 0.0;
+        self.probabilities()
+            .find(|(_, probability)| {
+                cumulative += probability;
+                cumulative >= target
+            })
+            .map_or_else(|| self.max(), |(value, _)| value)
+    }
+}
+
+impl Roll {
+    /// Exact probability of every result of this roll, including the effect
+    /// of any modifiers and selectors.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let distribution = Roll::new(2, Die::D20).keep_highest(1).distribution()?;
+    /// assert!((distribution.probability(20) - 39.0 / 400.0).abs() < 1e-9);
+    /// # Ok::<(), dice::DistributionError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the roll has too many possible outcomes to
+    /// calculate in a reasonable amount of time.
+    pub fn distribution(self) -> Result<ProbabilityDistribution, DistributionError> {
+        let die = self.die_distribution();
+        let kept = self.kept();
+        let too_complex = || DistributionError::TooComplex(self.to_string());
+
+        if kept == 0 {
+            return Ok(ProbabilityDistribution::constant(0));
+        }
+
+        // Without any dice being discarded, just add up every die
+        if kept == self.amount {
+            let work = self
+                .amount
+                .saturating_mul(self.amount)
+                .saturating_mul(die.probabilities.len().pow(2));
+            if work > MAX_WORK {
+                return Err(too_complex());
+            }
+            return Ok((1..self.amount).fold(die.clone(), |total, _| total.convolve(&die)));
+        }
+
+        let mut outcomes = die.probabilities().collect::<Vec<_>>();
+        let work = outcomes
+            .len()
+            .saturating_mul(self.amount.pow(2))
+            .saturating_mul(kept)
+            .saturating_mul(die.probabilities.len());
+        if work > MAX_WORK {
+            return Err(too_complex());
+        }
+
+        // Kept dice are chosen starting from one end of the possible values
+        if matches!(
+            self.selector,
+            Some(Selector::KeepHighest(_) | Selector::DropLowest(_))
+        ) {
+            outcomes.reverse();
+        }
+        Ok(select(&outcomes, self.amount, kept))
+    }
+
+    /// Probability of every value of a single die after modifiers
+    fn die_distribution(self) -> ProbabilityDistribution {
+        let die = ProbabilityDistribution::uniform(self.die.min().into(), self.die.max().into());
+        let max = i32::from(self.die.max());
+
+        let mut face = die.clone();
+        if let Some(below) = self.modifiers.reroll_below {
+            let below = i32::from(below);
+            let reroll = die
+                .probabilities()
+                .filter(|&(value, _)| value < below)
+                .map(|(_, probability)| probability)
+                .sum::<f64>();
+            face = ProbabilityDistribution::from_outcomes(die.probabilities().map(
+                |(value, probability)| {
+                    let kept = if value < below { 0.0 } else { probability };
+                    (value, kept + reroll * probability)
+                },
+            ));
+        }
+
+        if self.modifiers.explode {
+            // Extra value added by a die that has just rolled its max, with a
+            // given number of explosions left
+            let explosion = (0..MAX_EXPLOSIONS)
+                .fold(ProbabilityDistribution::constant(0), |next, _| {
+                    ProbabilityDistribution::from_outcomes(explode(&die, max, &next))
+                });
+            face = ProbabilityDistribution::from_outcomes(explode(&face, max, &explosion));
+        }
+
+        if let Some(minimum) = self.modifiers.minimum {
+            let minimum = i32::from(minimum);
+            face = ProbabilityDistribution::from_outcomes(
+                face.probabilities()
+                    .map(|(value, probability)| (value.max(minimum), probability)),
+            );
+        }
+
+        face
+    }
+}
+
+/// Outcomes of rolling a die where rolling the max adds the result of
+/// another distribution
+fn explode<'a>(
+    die: &'a ProbabilityDistribution,
+    max: i32,
+    explosion: &'a ProbabilityDistribution,
+) -> impl Iterator<Item = (i32, f64)> + 'a {
+    die.probabilities().flat_map(move |(value, probability)| {
+        let outcomes: Box<dyn Iterator<Item = (i32, f64)>> = if value == max {
+            Box::new(
+                explosion
+                    .probabilities()
+                    .map(move |(extra, p)| (max + extra, probability * p)),
+            )
+        } else {
+            Box::new([(value, probability)].into_iter())
+        };
+        outcomes
+    })
+}
+
+/// Distribution of the sum of the first `kept` dice out of `amount`, once
+/// they are sorted in the same order as `outcomes`.
+///
+/// Goes through each possible value in order, choosing how many of the
+/// remaining dice rolled it. Only the dice that fall within the first `kept`
+/// count towards the sum.
+fn select(outcomes: &[(i32, f64)], amount: usize, kept: usize) -> ProbabilityDistribution {
+    let count = |dice: usize| i32::try_from(dice).unwrap_or(i32::MAX);
+    let (low, high) = outcomes.iter().fold((0, 0), |(low, high), &(value, _)| {
+        (low.min(value), high.max(value))
+    });
+    let min = low * count(kept);
+    let len = ProbabilityDistribution::index((high - low) * count(kept)) + 1;
+
+    // Probability of each sum, for each number of dice that have been given
+    // a value so far
+    let mut sums = vec![vec![0.0; len]; amount + 1];
+    sums[0][ProbabilityDistribution::index(-min)] = 1.0;
+
+    for &(value, probability) in outcomes {
+        let mut next = sums.clone();
+        for (assigned, row) in sums.iter().enumerate() {
+            let remaining = amount - assigned;
+            // Probability of a specific number of the remaining dice rolling
+            // this value, built up one die at a time
+            let mut weight = 1.0;
+            for rolled in 1..=remaining {
+                weight *= probability * f64::from(count(remaining - rolled + 1))
+                    / f64::from(count(rolled));
+                let counted = rolled.min(kept.saturating_sub(assigned));
+                let offset = value * count(counted);
+                for (i, p) in row.iter().enumerate().filter(|(_, &p)| p > 0.0) {
+                    let sum = min + count(i) + offset;
+                    next[assigned + rolled][ProbabilityDistribution::index(sum - min)] +=
+                        p * weight;
+                }
+            }
+        }
+        sums = next;
+    }
+
+    ProbabilityDistribution {
+        min,
+        probabilities: sums.swap_remove(amount),
+    }
+}
+
+impl Expression {
+    /// Exact probability of every result of this expression
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let distribution = "1d4+1d4+2".parse::<Expression>()?.distribution()?;
+    /// assert_eq!(distribution.probability(7), 0.25);
+    /// assert_eq!(distribution.mean(), 7.0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the expression has too many possible outcomes
+    /// to calculate in a reasonable amount of time.
+    pub fn distribution(&self) -> Result<ProbabilityDistribution, DistributionError> {
+        self.terms()
+            .iter()
+            .try_fold(ProbabilityDistribution::constant(0), |total, term| {
+                let distribution = match *term {
+                    Term::Dice { sign, roll } => {
+                        let distribution = roll.distribution()?;
+                        match sign {
+                            Sign::Positive => distribution,
+                            Sign::Negative => distribution.negate(),
+                        }
+                    }
+                    Term::Modifier(value) => ProbabilityDistribution::constant(value),
+                };
+                if total
+                    .probabilities
+                    .len()
+                    .saturating_mul(distribution.probabilities.len())
+                    > MAX_WORK
+                {
+                    return Err(DistributionError::TooComplex(self.to_string()));
+                }
+                Ok(total.convolve(&distribution))
+            })
+    }
+}
+
+/// Errors caused while calculating a distribution
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum DistributionError {
+    /// The roll has too many possible outcomes to calculate
+    #[error("`{0}` has too many possible outcomes to calculate exact probabilities. Please try a roll with fewer dice.")]
+    TooComplex(String),
+}
@@ -0,0 +1,428 @@
+use std::{fmt, str::FromStr};
+
+use rand::Rng;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{Die, DieResult, Roll};
+
+/// Maximum amount of dice that can be rolled for a single term, to keep
+/// expressions from requesting an unreasonable amount of work.
+pub const MAX_DICE: usize = 1000;
+
+/// Whether a term is added to or subtracted from the total
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sign {
+    /// Add the term to the total
+    Positive,
+    /// Subtract the term from the total
+    Negative,
+}
+
+impl Sign {
+    /// Apply the sign to a given value
+    const fn apply(self, value: i32) -> i32 {
+        match self {
+            Self::Positive => value,
+            Self::Negative => -value,
+        }
+    }
+}
+
+impl fmt::Display for Sign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Positive => write!(f, "+"),
+            Self::Negative => write!(f, "-"),
+        }
+    }
+}
+
+/// An individual part of a dice expression
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Term {
+    /// Dice to be rolled and added up
+    Dice {
+        /// Whether to add or subtract the result
+        sign: Sign,
+        /// Dice to be rolled
+        roll: Roll,
+    },
+    /// A flat value to add to the total
+    Modifier(i32),
+}
+
+impl Term {
+    /// Minimum valid generatable value if rolled.
+    fn min(self) -> i32 {
+        match self {
+            Self::Dice { sign, roll } => match sign {
+                Sign::Positive => roll.min(),
+                Sign::Negative => sign.apply(roll.max()),
+            },
+            Self::Modifier(value) => value,
+        }
+    }
+
+    /// Maximum valid generatable value if rolled.
+    fn max(self) -> i32 {
+        match self {
+            Self::Dice { sign, roll } => match sign {
+                Sign::Positive => roll.max(),
+                Sign::Negative => sign.apply(roll.min()),
+            },
+            Self::Modifier(value) => value,
+        }
+    }
+
+    /// Roll the term and return the result
+    fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> TermResult {
+        match self {
+            Self::Dice { sign, roll } => {
+                let result = roll.roll(rng);
+                TermResult {
+                    term: self.to_string(),
+                    total: sign.apply(result.total()),
+                    kept: result.kept,
+                    discarded: result.discarded,
+                    dice: result.dice,
+                }
+            }
+            Self::Modifier(value) => TermResult {
+                term: self.to_string(),
+                total: value,
+                ..TermResult::default()
+            },
+        }
+    }
+
+    /// Parse an individual term, without its sign
+    fn parse(sign: Sign, term: &str) -> Result<Self, ParseExpressionError> {
+        let Some((amount, rest)) = term.split_once('d') else {
+            let value = parse_number::<i32>(term)?;
+            return Ok(Self::Modifier(sign.apply(value)));
+        };
+
+        let amount = if amount.is_empty() {
+            1
+        } else {
+            parse_number(amount)?
+        };
+
+        let sides_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == 'f' || c == '%'))
+            .unwrap_or(rest.len());
+        let (sides, mut suffixes) = rest.split_at(sides_end);
+        let mut roll = Roll::new(amount, parse_die(sides)?);
+
+        // Each suffix is a name, such as `kh` or `r<`, followed by a number
+        // if the suffix needs one
+        while !suffixes.is_empty() {
+            let name_end = if suffixes.starts_with('!') {
+                1
+            } else {
+                suffixes
+                    .find(|c: char| c.is_ascii_digit())
+                    .unwrap_or(suffixes.len())
+            };
+            let number_end = suffixes[name_end..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(suffixes.len(), |i| name_end + i);
+
+            roll = apply_suffix(roll, &suffixes[..name_end], &suffixes[name_end..number_end])?;
+            suffixes = &suffixes[number_end..];
+        }
+
+        Ok(Self::Dice { sign, roll })
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dice { roll, .. } => write!(f, "{roll}"),
+            Self::Modifier(value) => write!(f, "{}", value.unsigned_abs()),
+        }
+    }
+}
+
+/// Parse a number, providing a useful error if it isn't one
+fn parse_number<T: FromStr>(value: &str) -> Result<T, ParseExpressionError> {
+    value
+        .parse()
+        .map_err(|_| ParseExpressionError::InvalidNumber(value.to_owned()))
+}
+
+/// Parse the number of sides of a die, such as the `6` in `2d6`
+fn parse_die(sides: &str) -> Result<Die, ParseExpressionError> {
+    format!("d{sides}")
+        .parse()
+        .map_err(|_| ParseExpressionError::UnsupportedDie(sides.to_owned()))
+}
+
+/// Apply a suffix to a roll, such as `kh3`, `!`, `r<2` or `m10`
+fn apply_suffix(roll: Roll, name: &str, number: &str) -> Result<Roll, ParseExpressionError> {
+    let amount = || {
+        if number.is_empty() {
+            Ok(1)
+        } else {
+            parse_number(number)
+        }
+    };
+    let value = || parse_number(number);
+
+    Ok(match name {
+        "!" if number.is_empty() => roll.explode(),
+        "r<" => roll.reroll_below(value()?),
+        "m" => roll.minimum(value()?),
+        "k" | "kh" => roll.keep_highest(amount()?),
+        "kl" => roll.keep_lowest(amount()?),
+        "dh" => roll.drop_highest(amount()?),
+        "d" | "dl" => roll.drop_lowest(amount()?),
+        _ => return Err(ParseExpressionError::InvalidTerm(format!("{name}{number}"))),
+    })
+}
+
+/// A full dice expression, made up of dice and flat modifiers.
+/// Programmatic way of representing `2d6+1d4+3` for example.
+///
+/// ```
+/// use dice::Expression;
+///
+/// let mut rng = rand::thread_rng();
+/// let expression = "4d6kh3+2".parse::<Expression>()?;
+/// let result = expression.roll(&mut rng);
+///
+/// assert!((5..=20).contains(&result.total));
+/// # Ok::<(), dice::ParseExpressionError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Expression {
+    /// Terms to be rolled and added together
+    terms: Vec<Term>,
+}
+
+impl Expression {
+    /// Create a new expression out of individual terms
+    ///
+    /// ```
+    /// use dice::{Die, Expression, Roll, Sign, Term};
+    ///
+    /// let expression = Expression::new(vec![
+    ///     Term::Dice { sign: Sign::Positive, roll: Roll::new(1, Die::D20) },
+    ///     Term::Modifier(5),
+    /// ])?;
+    /// assert_eq!(expression.to_string(), "1d20+5");
+    /// # Ok::<(), dice::ParseExpressionError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if a term rolls more than [`MAX_DICE`] dice, or if
+    /// the total could overflow.
+    pub fn new(terms: Vec<Term>) -> Result<Self, ParseExpressionError> {
+        let expression = Self { terms };
+        expression.validate(|| expression.to_string())?;
+        Ok(expression)
+    }
+
+    /// Check that no term rolls too many dice, and that every running total
+    /// fits, so rolling can't overflow. `source` is the expression to report
+    /// in any error.
+    fn validate(&self, source: impl Fn() -> String) -> Result<(), ParseExpressionError> {
+        let (mut min, mut max) = (0i32, 0i32);
+        for term in &self.terms {
+            if let Term::Dice { roll, .. } = term {
+                if roll.amount > MAX_DICE {
+                    return Err(ParseExpressionError::TooManyDice(roll.amount));
+                }
+            }
+            min = min
+                .checked_add(term.min())
+                .ok_or_else(|| ParseExpressionError::OutOfRange(source()))?;
+            max = max
+                .checked_add(term.max())
+                .ok_or_else(|| ParseExpressionError::OutOfRange(source()))?;
+        }
+        Ok(())
+    }
+
+    /// Terms that make up this expression
+    #[must_use]
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// Roll all of the dice in the expression and add up the total
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let result = "2d6+1d4+3".parse::<Expression>()?.roll(&mut rng);
+    ///
+    /// assert_eq!(result.terms.len(), 3);
+    /// # Ok::<(), dice::ParseExpressionError>(())
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> ExpressionResult {
+        let terms = self.terms.iter().map(|t| t.roll(rng)).collect::<Vec<_>>();
+
+        ExpressionResult {
+            expression: self.to_string(),
+            total: terms.iter().map(|t| t.total).fold(0, i32::saturating_add),
+            terms,
+        }
+    }
+
+    /// Minimum valid generatable value if rolled.
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let min = "2d6-1d4+3".parse::<Expression>()?.min();
+    /// assert_eq!(min, 1);
+    /// # Ok::<(), dice::ParseExpressionError>(())
+    /// ```
+    #[must_use]
+    pub fn min(&self) -> i32 {
+        self.terms
+            .iter()
+            .map(|t| t.min())
+            .fold(0, i32::saturating_add)
+    }
+
+    /// Maximum valid generatable value if rolled.
+    ///
+    /// ```
+    /// use dice::Expression;
+    ///
+    /// let max = "2d6-1d4+3".parse::<Expression>()?.max();
+    /// assert_eq!(max, 14);
+    /// # Ok::<(), dice::ParseExpressionError>(())
+    /// ```
+    #[must_use]
+    pub fn max(&self) -> i32 {
+        self.terms
+            .iter()
+            .map(|t| t.max())
+            .fold(0, i32::saturating_add)
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            let sign = match term {
+                Term::Dice { sign, .. } => *sign,
+                Term::Modifier(value) if value.is_negative() => Sign::Negative,
+                Term::Modifier(_) => Sign::Positive,
+            };
+            if i > 0 || sign == Sign::Negative {
+                write!(f, "{sign}")?;
+            }
+            write!(f, "{term}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseExpressionError;
+
+    /// Parse an expression such as `2d6 + 1d4 - 1`. Whitespace is allowed
+    /// around signs, but not inside a term, so `1d20 5` is an error rather
+    /// than `1d205`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expression = s.trim().to_lowercase();
+        if expression.is_empty() {
+            return Err(ParseExpressionError::Empty);
+        }
+
+        let mut terms = vec![];
+        let mut sign = Sign::Positive;
+        let mut term = String::new();
+        // Whether whitespace has ended the current term
+        let mut term_ended = false;
+        for (i, c) in expression.chars().chain(['+']).enumerate() {
+            match c {
+                '+' | '-' => {
+                    if term.is_empty() {
+                        // Only a leading sign is allowed without a term before it
+                        if i > 0 {
+                            return Err(ParseExpressionError::InvalidTerm(expression));
+                        }
+                    } else {
+                        terms.push(Term::parse(sign, &term)?);
+                        term.clear();
+                    }
+                    term_ended = false;
+                    sign = if c == '-' {
+                        Sign::Negative
+                    } else {
+                        Sign::Positive
+                    };
+                }
+                c if c.is_whitespace() => term_ended = !term.is_empty(),
+                _ if term_ended => return Err(ParseExpressionError::MissingSign(expression)),
+                _ => term.push(c),
+            }
+        }
+
+        let parsed = Self { terms };
+        parsed.validate(|| expression.clone())?;
+        Ok(parsed)
+    }
+}
+
+/// Result of rolling an individual term in an expression
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TermResult {
+    /// The term that was rolled
+    pub term: String,
+    /// Dice that were rolled and count towards the total
+    pub kept: Vec<i16>,
+    /// Dice that were rolled but discarded by a selector
+    pub discarded: Vec<i16>,
+    /// Every intermediate roll for each die, including rerolls and explosions
+    pub dice: Vec<DieResult>,
+    /// Total value this term added to the expression
+    pub total: i32,
+}
+
+/// Result of rolling an entire expression, with a breakdown of each term
+#[derive(Clone, Debug, Serialize)]
+pub struct ExpressionResult {
+    /// The expression that was rolled
+    pub expression: String,
+    /// Total of all of the terms added together
+    pub total: i32,
+    /// Breakdown of the results of each term
+    pub terms: Vec<TermResult>,
+}
+
+/// Errors caused by an invalid dice expression
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseExpressionError {
+    /// No terms were provided
+    #[error("The dice expression is empty. Please provide at least one term, such as `1d20`.")]
+    Empty,
+    /// A term couldn't be understood
+    #[error("Invalid term in dice expression: `{0}`.")]
+    InvalidTerm(String),
+    /// A number was expected but couldn't be parsed
+    #[error("Invalid number in dice expression: `{0}`.")]
+    InvalidNumber(String),
+    /// Too many dice were requested for a single term
+    #[error("Too many dice requested: {0}. A single term can roll at most {MAX_DICE} dice.")]
+    TooManyDice(usize),
+    /// The requested die doesn't exist
+    #[error("Unsupported die: `d{0}`.")]
+    UnsupportedDie(String),
+    /// Two terms were next to each other without a `+` or `-` between them
+    #[error("Missing `+` or `-` between terms in dice expression: `{0}`.")]
+    MissingSign(String),
+    /// The total of the expression could be too large to calculate
+    #[error("The dice expression `{0}` could total more than {max} or less than {min}.", max = i32::MAX, min = i32::MIN)]
+    OutOfRange(String),
+}
@@ -1,7 +1,12 @@
 //! # Dice
 //!
 //! `dice` contains everything you need to roll some dice.
-//! Supports d4, d6, d8, d10, d12, d20, d100
+//! Supports d4, d6, d8, d10, d12, d20, d100, dice with any other number of
+//! sides, and Fudge dice, as well as full dice expressions such as
+//! `2d6+1d4+3`. Rolls can keep or drop dice, explode, reroll low values, or
+//! have a minimum value. Exact probabilities of any roll can be calculated
+//! without rolling at all. Dice pools count successes against a target
+//! number instead of adding dice together.
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -14,14 +19,29 @@
     unused
 )]
 
+use std::{fmt, str::FromStr};
+
+use itertools::Itertools;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter};
+use thiserror::Error;
+
+pub use crate::{
+    distribution::{DistributionError, ProbabilityDistribution},
+    expression::{
+        Expression, ExpressionResult, ParseExpressionError, Sign, Term, TermResult, MAX_DICE,
+    },
+    pool::{Pool, PoolOutcome, PoolResult},
+};
+
+mod distribution;
+mod expression;
+mod pool;
 
 /// Available dice types for rolling
-#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "lowercase")]
-#[strum(serialize_all = "lowercase")]
+#[derive(Clone, Copy, Debug, Deserialize, EnumIter, Eq, Hash, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
 pub enum Die {
     /// Four-sided die
     D4,
@@ -37,9 +57,121 @@ pub enum Die {
     D20,
     /// Equivalent of rolling two d10s for a d100 table.
     D100,
+    /// Fate/Fudge die, with two blank sides, two plus sides, and two minus
+    /// sides. Rolls -1, 0, or +1.
+    Fudge,
+    /// Die with any other number of sides, such as a d2, d3, or d30.
+    /// Created with [`Die::with_sides`].
+    #[strum(disabled)]
+    Custom(CustomSides),
+}
+
+/// Number of sides of a [`Die::Custom`]. Only created by [`Die::with_sides`],
+/// so it is never zero, more than [`Die::MAX_SIDES`], or the number of sides
+/// of one of the named dice.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CustomSides(u8);
+
+impl CustomSides {
+    /// Number of sides
+    #[must_use]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
 }
 
 impl Die {
+    /// Most sides a die can have. Small enough that the number of sides fits
+    /// in any integer type.
+    pub const MAX_SIDES: u8 = 127;
+
+    /// Die with the given number of sides. Uses the named variant if one
+    /// exists, so that `Die::with_sides(6) == Some(Die::D6)`.
+    ///
+    /// Returns `None` for a die with no sides, or more than
+    /// [`Die::MAX_SIDES`].
+    ///
+    /// ```
+    /// use dice::Die;
+    ///
+    /// assert_eq!(Die::with_sides(20), Some(Die::D20));
+    /// assert_eq!(Die::with_sides(3).unwrap().sides(), 3);
+    /// assert_eq!(Die::with_sides(0), None);
+    /// assert_eq!(Die::with_sides(200), None);
+    /// ```
+    #[must_use]
+    pub const fn with_sides(sides: u8) -> Option<Self> {
+        match sides {
+            0 => None,
+            sides if sides > Self::MAX_SIDES => None,
+            4 => Some(Self::D4),
+            6 => Some(Self::D6),
+            8 => Some(Self::D8),
+            10 => Some(Self::D10),
+            12 => Some(Self::D12),
+            20 => Some(Self::D20),
+            100 => Some(Self::D100),
+            sides => Some(Self::Custom(CustomSides(sides))),
+        }
+    }
+
+    /// Number of sides on the die
+    ///
+    /// ```
+    /// use dice::Die;
+    ///
+    /// assert_eq!(Die::D8.sides(), 8);
+    /// assert_eq!(Die::Fudge.sides(), 3);
+    /// ```
+    #[must_use]
+    pub const fn sides(self) -> u8 {
+        match self {
+            Self::D4 => 4,
+            Self::D6 => 6,
+            Self::D8 => 8,
+            Self::D10 => 10,
+            Self::D12 => 12,
+            Self::D20 => 20,
+            Self::D100 => 100,
+            // Six physical sides, but only three distinct faces
+            Self::Fudge => 3,
+            Self::Custom(sides) => sides.get(),
+        }
+    }
+
+    /// Lowest value the die can roll
+    ///
+    /// ```
+    /// use dice::Die;
+    ///
+    /// assert_eq!(Die::D6.min(), 1);
+    /// assert_eq!(Die::Fudge.min(), -1);
+    /// ```
+    #[must_use]
+    pub const fn min(self) -> i16 {
+        match self {
+            Self::Fudge => -1,
+            _ => 1,
+        }
+    }
+
+    /// Highest value the die can roll
+    ///
+    /// ```
+    /// use dice::Die;
+    ///
+    /// assert_eq!(Die::D6.max(), 6);
+    /// assert_eq!(Die::Fudge.max(), 1);
+    /// ```
+    #[must_use]
+    pub const fn max(self) -> i16 {
+        match self {
+            Self::Fudge => 1,
+            // Can't use `i16::from` in a const fn
+            _ => self.sides() as i16,
+        }
+    }
+
     /// Roll the die and return the result
     ///
     /// ```
@@ -50,8 +182,8 @@ impl Die {
     ///
     /// assert!((1..=20).contains(&roll));
     /// ```
-    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> u8 {
-        let roll = rng.gen_range(1u8..=self.into());
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> i16 {
+        let roll = rng.gen_range(self.min()..=self.max());
 
         metrics::counter!(
             "dice_roll_total",
@@ -77,54 +209,205 @@ impl Die {
         self,
         rng: &mut R,
         amount: usize,
-    ) -> impl Iterator<Item = u8> + '_ {
+    ) -> impl Iterator<Item = i16> + '_ {
         (1..=amount).map(move |_| self.roll(rng))
     }
+
+    /// Roll the die twice, keeping the higher roll for advantage or the lower
+    /// roll for disadvantage. Both rolls are returned in the result.
+    ///
+    /// ```
+    /// use dice::{Die, RollMode};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let result = Die::D20.roll_with_mode(&mut rng, RollMode::Advantage);
+    ///
+    /// assert!(result.kept[0] >= result.discarded[0]);
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn roll_with_mode<R: Rng + ?Sized>(self, rng: &mut R, mode: RollMode) -> RollResult {
+        Roll::with_mode(self, mode).roll(rng)
+    }
+}
+
+impl fmt::Display for Die {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fudge => write!(f, "dF"),
+            _ => write!(f, "d{}", self.sides()),
+        }
+    }
+}
+
+impl FromStr for Die {
+    type Err = ParseDieError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+        match lowercase.strip_prefix('d') {
+            Some("f") => Ok(Self::Fudge),
+            Some("%") => Ok(Self::D100),
+            Some(sides) => sides
+                .parse()
+                .ok()
+                .and_then(Self::with_sides)
+                .ok_or_else(|| ParseDieError(s.to_owned())),
+            None => Err(ParseDieError(s.to_owned())),
+        }
+    }
+}
+
+impl TryFrom<String> for Die {
+    type Error = ParseDieError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
 }
 
-macro_rules! impl_int_from_die {
-    ($($int_type: ty),*) => {
+impl From<Die> for String {
+    fn from(die: Die) -> Self {
+        die.to_string()
+    }
+}
+
+/// Error caused by an invalid or unsupported die, such as `d0` or `x6`
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Unsupported die: `{0}`. Dice should be written as `d` followed by up to {max} sides, such as `d6`, or as `dF` for Fudge dice.", max = Die::MAX_SIDES)]
+pub struct ParseDieError(String);
+
+/// Some circumstances call for rolling a die twice and taking either the
+/// higher or lower of the two results.
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RollMode {
+    /// Roll twice and use the higher roll
+    Advantage,
+    /// Roll twice and use the lower roll
+    Disadvantage,
+}
+
+macro_rules! impl_from_die {
+    ($($num_type: ty),*) => {
         $(
-            impl From<Die> for $int_type {
-                fn from(die: Die) -> $int_type {
-                    match die {
-                        Die::D4 => 4,
-                        Die::D6 => 6,
-                        Die::D8 => 8,
-                        Die::D10 => 10,
-                        Die::D12 => 12,
-                        Die::D20 => 20,
-                        Die::D100 => 100,
-                    }
+            /// Number of sides of the die, the same as [`Die::sides`]. Fudge
+            /// dice have 3, their number of distinct faces, so use
+            /// [`Die::min`] and [`Die::max`] for the values a die can roll.
+            impl From<Die> for $num_type {
+                fn from(die: Die) -> $num_type {
+                    die.sides().into()
                 }
             }
         )*
     }
 }
 
-impl_int_from_die!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_from_die!(u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64);
 
-macro_rules! impl_float_from_die {
-    ($($float_type: ty),*) => {
-        $(
-            impl From<Die> for $float_type {
-                fn from(die: Die) -> $float_type {
-                    match die {
-                        Die::D4 => 4.0,
-                        Die::D6 => 6.0,
-                        Die::D8 => 8.0,
-                        Die::D10 => 10.0,
-                        Die::D12 => 12.0,
-                        Die::D20 => 20.0,
-                        Die::D100 => 100.0,
-                    }
+/// Number of sides of the die, the same as [`Die::sides`]. Fudge dice have
+/// 3, their number of distinct faces, so use [`Die::min`] and [`Die::max`]
+/// for the values a die can roll.
+impl From<Die> for i8 {
+    fn from(die: Die) -> i8 {
+        // Dice have at most `Die::MAX_SIDES` sides, so this can't wrap
+        #[allow(clippy::cast_possible_wrap)]
+        let sides = die.sides() as i8;
+        sides
+    }
+}
+
+/// Which dice to keep from a roll when adding up the total, such as the `kh3`
+/// in `4d6kh3`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Selector {
+    /// Keep the given number of highest dice
+    KeepHighest(usize),
+    /// Keep the given number of lowest dice
+    KeepLowest(usize),
+    /// Discard the given number of highest dice
+    DropHighest(usize),
+    /// Discard the given number of lowest dice
+    DropLowest(usize),
+}
+
+impl Selector {
+    /// Number of dice that will be kept out of a given amount rolled
+    const fn kept(self, amount: usize) -> usize {
+        match self {
+            Self::KeepHighest(keep) | Self::KeepLowest(keep) => {
+                if keep < amount {
+                    keep
+                } else {
+                    amount
                 }
             }
-        )*
+            Self::DropHighest(drop) | Self::DropLowest(drop) => amount.saturating_sub(drop),
+        }
+    }
+
+    /// Indices of the rolls that should be discarded
+    fn discarded(self, rolls: &[i16]) -> Vec<usize> {
+        // Indices ordered from lowest to highest roll
+        let ascending = (0..rolls.len()).sorted_by_key(|&i| rolls[i]);
+        let discard = rolls.len() - self.kept(rolls.len());
+
+        match self {
+            Self::KeepHighest(_) | Self::DropLowest(_) => ascending.take(discard).collect(),
+            Self::KeepLowest(_) | Self::DropHighest(_) => ascending.rev().take(discard).collect(),
+        }
     }
 }
 
-impl_float_from_die!(f32, f64);
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeepHighest(amount) => write!(f, "kh{amount}"),
+            Self::KeepLowest(amount) => write!(f, "kl{amount}"),
+            Self::DropHighest(amount) => write!(f, "dh{amount}"),
+            Self::DropLowest(amount) => write!(f, "dl{amount}"),
+        }
+    }
+}
+
+/// Maximum number of times a single exploding die can explode, so that a
+/// roll is guaranteed to finish.
+pub const MAX_EXPLOSIONS: u8 = 10;
+
+/// Rules applied to each individual die as it is rolled
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Modifiers {
+    /// Roll again and add the result every time the die rolls its maximum
+    explode: bool,
+    /// Reroll the die once if it rolls below this value, and use the new roll
+    reroll_below: Option<i16>,
+    /// Treat any roll lower than this value as this value
+    minimum: Option<i16>,
+}
+
+impl Modifiers {
+    /// No modifiers applied to the dice
+    const NONE: Self = Self {
+        explode: false,
+        reroll_below: None,
+        minimum: None,
+    };
+}
+
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.explode {
+            write!(f, "!")?;
+        }
+        if let Some(reroll_below) = self.reroll_below {
+            write!(f, "r<{reroll_below}")?;
+        }
+        if let Some(minimum) = self.minimum {
+            write!(f, "m{minimum}")?;
+        }
+        Ok(())
+    }
+}
 
 /// A way to represent a roll that should be carried out.
 /// Programmatic way of representing 2d6 for example.
@@ -137,12 +420,16 @@ impl_float_from_die!(f32, f64);
 ///
 /// assert_eq!(rolls.count(), 2);
 /// ```
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Roll {
     /// Amount of die to be rolled
     amount: usize,
     /// Die to use in the roll
     die: Die,
+    /// Rules applied to each die as it is rolled
+    modifiers: Modifiers,
+    /// Which dice to keep in the total, if not all of them
+    selector: Option<Selector>,
 }
 
 impl Roll {
@@ -155,10 +442,225 @@ impl Roll {
     /// ````
     #[must_use]
     pub const fn new(amount: usize, die: Die) -> Self {
-        Self { amount, die }
+        Self {
+            amount,
+            die,
+            modifiers: Modifiers::NONE,
+            selector: None,
+        }
+    }
+
+    /// Create a roll of two of the given die, keeping the higher or lower
+    /// roll depending on the mode.
+    ///
+    /// ```
+    /// use dice::{Die, Roll, RollMode};
+    ///
+    /// let roll = Roll::with_mode(Die::D20, RollMode::Disadvantage);
+    /// assert_eq!(roll.to_string(), "2d20kl1");
+    /// ```
+    #[must_use]
+    pub const fn with_mode(die: Die, mode: RollMode) -> Self {
+        let roll = Self::new(2, die);
+        match mode {
+            RollMode::Advantage => roll.keep_highest(1),
+            RollMode::Disadvantage => roll.keep_lowest(1),
+        }
+    }
+
+    /// Exploding dice: every time a die rolls its maximum value, roll it
+    /// again and add the new roll to its value. A single die will explode at
+    /// most [`MAX_EXPLOSIONS`] times.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let roll = Roll::new(3, Die::D6).explode();
+    /// assert_eq!(roll.to_string(), "3d6!");
+    /// ```
+    #[must_use]
+    pub const fn explode(mut self) -> Self {
+        self.modifiers.explode = true;
+        self
+    }
+
+    /// Reroll any die that rolls below the given value once, and use the new
+    /// roll even if it is also below the value. For example, Great Weapon
+    /// Fighting rerolls 1s and 2s, and the Halfling's Lucky trait rerolls 1s.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// // Great Weapon Fighting with a greatsword
+    /// let roll = Roll::new(2, Die::D6).reroll_below(3);
+    /// assert_eq!(roll.to_string(), "2d6r<3");
+    /// ```
+    #[must_use]
+    pub const fn reroll_below(mut self, value: i16) -> Self {
+        self.modifiers.reroll_below = Some(value);
+        self
+    }
+
+    /// Treat any die that rolls lower than the given value as that value,
+    /// such as a rogue's Reliable Talent.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// // Reliable Talent
+    /// let roll = Roll::new(1, Die::D20).minimum(10);
+    /// assert_eq!(roll.min(), 10);
+    /// ```
+    #[must_use]
+    pub const fn minimum(mut self, value: i16) -> Self {
+        self.modifiers.minimum = Some(value);
+        self
+    }
+
+    /// Choose which dice will be kept when the roll is added up
+    ///
+    /// ```
+    /// use dice::{Die, Roll, Selector};
+    ///
+    /// let roll = Roll::new(4, Die::D6).select(Selector::DropLowest(1));
+    /// assert_eq!(roll.to_string(), "4d6dl1");
+    /// ```
+    #[must_use]
+    pub const fn select(mut self, selector: Selector) -> Self {
+        self.selector = Some(selector);
+        self
+    }
+
+    /// Keep only the given number of highest dice
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let roll = Roll::new(4, Die::D6).keep_highest(3);
+    /// assert_eq!(roll.max(), 18);
+    /// ```
+    #[must_use]
+    pub const fn keep_highest(self, amount: usize) -> Self {
+        self.select(Selector::KeepHighest(amount))
+    }
+
+    /// Keep only the given number of lowest dice
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let roll = Roll::new(2, Die::D20).keep_lowest(1);
+    /// assert_eq!(roll.max(), 20);
+    /// ```
+    #[must_use]
+    pub const fn keep_lowest(self, amount: usize) -> Self {
+        self.select(Selector::KeepLowest(amount))
+    }
+
+    /// Discard the given number of highest dice
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let roll = Roll::new(3, Die::D8).drop_highest(1);
+    /// assert_eq!(roll.max(), 16);
+    /// ```
+    #[must_use]
+    pub const fn drop_highest(self, amount: usize) -> Self {
+        self.select(Selector::DropHighest(amount))
     }
 
-    /// Roll the specified dice
+    /// Discard the given number of lowest dice
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let roll = Roll::new(4, Die::D6).drop_lowest(1);
+    /// assert_eq!(roll.min(), 3);
+    /// ```
+    #[must_use]
+    pub const fn drop_lowest(self, amount: usize) -> Self {
+        self.select(Selector::DropLowest(amount))
+    }
+
+    /// Number of dice that will count towards the total
+    const fn kept(self) -> usize {
+        match self.selector {
+            Some(selector) => selector.kept(self.amount),
+            None => self.amount,
+        }
+    }
+
+    /// Roll the specified dice, returning the dice that were kept and
+    /// discarded by the selector, if any.
+    ///
+    /// ```
+    /// use dice::{Die, Roll};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let result = Roll::new(4, Die::D6).drop_lowest(1).roll(&mut rng);
+    ///
+    /// assert_eq!(result.kept.len(), 3);
+    /// assert_eq!(result.discarded.len(), 1);
+    /// assert!(result.kept.iter().all(|&k| k >= result.discarded[0]));
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> RollResult {
+        let dice = (0..self.amount)
+            .map(|_| self.roll_die(rng))
+            .collect::<Vec<_>>();
+        let values = dice.iter().map(|d| d.value).collect::<Vec<_>>();
+        let discarded = self
+            .selector
+            .map(|s| s.discarded(&values))
+            .unwrap_or_default();
+
+        let (discarded, kept) = values
+            .into_iter()
+            .enumerate()
+            .partition::<Vec<_>, _>(|(i, _)| discarded.contains(i));
+
+        RollResult {
+            kept: kept.into_iter().map(|(_, r)| r).collect(),
+            discarded: discarded.into_iter().map(|(_, r)| r).collect(),
+            dice,
+        }
+    }
+
+    /// Roll a single die, applying any rerolls, explosions, and minimums
+    fn roll_die<R: Rng + ?Sized>(self, rng: &mut R) -> DieResult {
+        let mut rolls = vec![self.die.roll(rng)];
+
+        let mut last = rolls[0];
+        if self
+            .modifiers
+            .reroll_below
+            .is_some_and(|below| last < below)
+        {
+            last = self.die.roll(rng);
+            rolls.push(last);
+        }
+
+        let mut value = last;
+        if self.modifiers.explode {
+            let mut explosions = 0;
+            while last == self.die.max() && explosions < MAX_EXPLOSIONS {
+                last = self.die.roll(rng);
+                rolls.push(last);
+                value += last;
+                explosions += 1;
+            }
+        }
+
+        if let Some(minimum) = self.modifiers.minimum {
+            value = value.max(minimum);
+        }
+
+        DieResult { value, rolls }
+    }
+
+    /// Roll the specified dice, returning the dice that count towards the
+    /// total.
     ///
     /// ```
     /// use dice::{Die, Roll};
@@ -169,8 +671,8 @@ impl Roll {
     /// assert_eq!(rolls.count(), 2);
     /// ```
     #[tracing::instrument(skip(rng))]
-    pub fn gen<R: Rng + ?Sized>(self, rng: &mut R) -> impl Iterator<Item = u8> + '_ {
-        self.die.roll_multiple(rng, self.amount)
+    pub fn gen<R: Rng + ?Sized>(self, rng: &mut R) -> impl Iterator<Item = i16> {
+        self.roll(rng).kept.into_iter()
     }
 
     /// Minimum valid generatable value if rolled.
@@ -182,8 +684,8 @@ impl Roll {
     /// assert_eq!(min, 2);
     /// ```
     #[must_use]
-    pub fn min(self) -> usize {
-        self.amount
+    pub fn min(self) -> i32 {
+        Self::count(self.kept()) * self.die_min()
     }
 
     /// Maximum valid generatable value if rolled.
@@ -195,7 +697,81 @@ impl Roll {
     /// assert_eq!(max, 12);
     /// ```
     #[must_use]
-    pub fn max(self) -> usize {
-        self.amount * usize::from(self.die)
+    pub fn max(self) -> i32 {
+        Self::count(self.kept()) * self.die_max()
+    }
+
+    /// Minimum value of a single die after modifiers
+    fn die_min(self) -> i32 {
+        let min = i32::from(self.die.min());
+        self.modifiers
+            .minimum
+            .map_or(min, |minimum| min.max(minimum.into()))
+    }
+
+    /// Maximum value of a single die after modifiers
+    fn die_max(self) -> i32 {
+        let explosions = if self.modifiers.explode {
+            i32::from(MAX_EXPLOSIONS)
+        } else {
+            0
+        };
+        let max = i32::from(self.die.max()) * (1 + explosions);
+        self.modifiers
+            .minimum
+            .map_or(max, |minimum| max.max(minimum.into()))
+    }
+
+    /// Convert an amount of dice into a number that can be multiplied by a
+    /// die value
+    fn count(amount: usize) -> i32 {
+        i32::try_from(amount).unwrap_or(i32::MAX)
+    }
+}
+
+impl fmt::Display for Roll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.amount, self.die, self.modifiers)?;
+        if let Some(selector) = self.selector {
+            write!(f, "{selector}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Every roll made for a single die, and the value it ended up with
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct DieResult {
+    /// Final value of the die, after rerolls, explosions, and minimums
+    pub value: i16,
+    /// Every roll made for this die, in order, including rerolls and
+    /// explosions
+    pub rolls: Vec<i16>,
+}
+
+/// Results of a roll, split into the dice that count towards the total and
+/// the dice that were discarded.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct RollResult {
+    /// Dice that count towards the total, in the order they were rolled
+    pub kept: Vec<i16>,
+    /// Dice that were discarded by the selector, in the order they were rolled
+    pub discarded: Vec<i16>,
+    /// Every intermediate roll for each die, in the order they were rolled
+    pub dice: Vec<DieResult>,
+}
+
+impl RollResult {
+    /// Sum of all of the kept dice
+    ///
+    /// ```
+    /// use dice::RollResult;
+    ///
+    /// let result = RollResult { kept: vec![6, 5, 4], discarded: vec![1], ..RollResult::default() };
+    /// assert_eq!(result.total(), 15);
+    /// ```
+    #[must_use]
+    pub fn total(&self) -> i32 {
+        self.kept.iter().map(|&r| i32::from(r)).sum()
     }
 }
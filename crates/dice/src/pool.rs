@@ -0,0 +1,182 @@
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::Die;
+
+/// A pool of dice where each die that meets a target number counts as a
+/// success, instead of adding the dice together. Programmatic way of
+/// representing `5d10>=6` for example.
+///
+/// ```
+/// use dice::{Die, Pool};
+///
+/// let mut rng = rand::thread_rng();
+/// let result = Pool::new(5, Die::D10, 6).roll(&mut rng);
+///
+/// assert_eq!(result.rolls.len(), 5);
+/// assert!(result.successes <= 5);
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub struct Pool {
+    /// Amount of dice in the pool
+    amount: usize,
+    /// Die to use for every die in the pool
+    #[serde(default = "default_die")]
+    die: Die,
+    /// Rolls greater than or equal to this value are a success
+    target: i16,
+    /// Each 1 rolled cancels out a success
+    #[serde(default)]
+    subtract_ones: bool,
+    /// Rolling no successes and at least one 1 is a botch
+    #[serde(default)]
+    botch: bool,
+}
+
+/// Dice pools are usually made up of d10s
+const fn default_die() -> Die {
+    Die::D10
+}
+
+impl Pool {
+    /// Create a new pool of dice with a target number to be rolled later
+    ///
+    /// ```
+    /// use dice::{Die, Pool};
+    ///
+    /// let pool = Pool::new(6, Die::D10, 7);
+    /// assert_eq!(pool.to_string(), "6d10>=7");
+    /// ```
+    #[must_use]
+    pub const fn new(amount: usize, die: Die, target: i16) -> Self {
+        Self {
+            amount,
+            die,
+            target,
+            subtract_ones: false,
+            botch: false,
+        }
+    }
+
+    /// Each 1 rolled cancels out one success. Net successes never go below
+    /// zero.
+    ///
+    /// ```
+    /// use dice::{Die, Pool};
+    ///
+    /// let pool = Pool::new(6, Die::D10, 7).subtract_ones();
+    /// assert_eq!(pool.to_string(), "6d10>=7-1s");
+    /// ```
+    #[must_use]
+    pub const fn subtract_ones(mut self) -> Self {
+        self.subtract_ones = true;
+        self
+    }
+
+    /// A roll with no successes and at least one 1 is a botch, rather than a
+    /// plain failure.
+    ///
+    /// ```
+    /// use dice::{Die, Pool};
+    ///
+    /// let pool = Pool::new(6, Die::D10, 7).botch();
+    /// assert_eq!(pool.to_string(), "6d10>=7b");
+    /// ```
+    #[must_use]
+    pub const fn botch(mut self) -> Self {
+        self.botch = true;
+        self
+    }
+
+    /// Amount of dice in the pool
+    #[must_use]
+    pub const fn amount(self) -> usize {
+        self.amount
+    }
+
+    /// Roll every die in the pool and count up the successes
+    ///
+    /// ```
+    /// use dice::{Die, Pool, PoolOutcome};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let result = Pool::new(3, Die::D10, 11).roll(&mut rng);
+    ///
+    /// assert_eq!(result.successes, 0);
+    /// assert_eq!(result.outcome, PoolOutcome::Failure);
+    /// ```
+    #[tracing::instrument(skip(rng))]
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> PoolResult {
+        let rolls = self.die.roll_multiple(rng, self.amount).collect::<Vec<_>>();
+        let successes = rolls.iter().filter(|&&r| r >= self.target).count();
+        let ones = rolls.iter().filter(|&&r| r == 1).count();
+
+        let net_successes = if self.subtract_ones {
+            successes.saturating_sub(ones)
+        } else {
+            successes
+        };
+        let outcome = if net_successes > 0 {
+            PoolOutcome::Success
+        } else if self.botch && successes == 0 && ones > 0 {
+            PoolOutcome::Botch
+        } else {
+            PoolOutcome::Failure
+        };
+
+        PoolResult {
+            failures: rolls.len() - successes,
+            rolls,
+            successes,
+            ones,
+            net_successes,
+            outcome,
+        }
+    }
+}
+
+impl fmt::Display for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}>={}", self.amount, self.die, self.target)?;
+        if self.subtract_ones {
+            write!(f, "-1s")?;
+        }
+        if self.botch {
+            write!(f, "b")?;
+        }
+        Ok(())
+    }
+}
+
+/// Overall outcome of rolling a dice pool
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum PoolOutcome {
+    /// At least one net success
+    Success,
+    /// No net successes
+    Failure,
+    /// No successes at all, and at least one 1 was rolled
+    Botch,
+}
+
+/// Results of rolling a dice pool
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PoolResult {
+    /// Every die rolled, in the order they were rolled
+    pub rolls: Vec<i16>,
+    /// Number of dice that met the target
+    pub successes: usize,
+    /// Number of dice that didn't meet the target
+    pub failures: usize,
+    /// Number of dice that rolled a 1
+    pub ones: usize,
+    /// Successes left after any 1s have cancelled them out
+    pub net_successes: usize,
+    /// Overall outcome of the roll
+    pub outcome: PoolOutcome,
+}
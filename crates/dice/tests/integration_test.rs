@@ -1,3 +1,4 @@
+//! Integration tests for the `dice` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -10,7 +11,13 @@
     unused
 )]
 
-use dice::{Die, Roll};
+use std::collections::HashMap;
+
+use dice::{
+    Die, DieResult, DistributionError, Expression, ParseExpressionError, Pool, PoolOutcome, Roll,
+    RollMode, RollResult, Sign, Term, MAX_EXPLOSIONS,
+};
+use itertools::Itertools;
 use rand::{seq::IteratorRandom, Rng};
 use statrs::{
     distribution::Uniform,
@@ -18,18 +25,29 @@ use statrs::{
 };
 use strum::IntoEnumIterator;
 
+/// All named dice, as well as a few custom dice
+fn all_dice() -> impl Iterator<Item = Die> {
+    Die::iter().chain([
+        Die::with_sides(2).unwrap(),
+        Die::with_sides(3).unwrap(),
+        Die::with_sides(30).unwrap(),
+    ])
+}
+
 #[test]
 fn roll() {
     let mut rng = rand_utils::rng_from_entropy();
 
-    for die in Die::iter() {
-        let dist = Uniform::new(1.0, die.into()).unwrap();
+    for die in all_dice() {
+        let dist = Uniform::new(die.min().into(), die.max().into()).unwrap();
 
         let rolls = (0..u32::from(die) * 10)
             .map(|_| die.roll(&mut rng))
             .collect::<Vec<_>>();
 
-        assert!(rolls.iter().all(|roll| (1..=die.into()).contains(roll)));
+        assert!(rolls
+            .iter()
+            .all(|roll| (die.min()..=die.max()).contains(roll)));
         let mean = rolls.iter().map(|&r| f64::from(r)).mean();
         assert!((mean - dist.mean().unwrap()).abs() < dist.std_dev().unwrap());
     }
@@ -38,12 +56,34 @@ fn roll() {
 #[test]
 fn roll_multiple() {
     let mut rng = rand_utils::rng_from_entropy();
-    for die in Die::iter() {
+    for die in all_dice() {
         // Roll a normal range of dice
         for i in 1..=12 {
             let rolls = die.roll_multiple(&mut rng, i).collect::<Vec<_>>();
             assert_eq!(rolls.len(), i);
-            assert!(rolls.iter().all(|d| (1..=die.into()).contains(d)));
+            assert!(rolls.iter().all(|d| (die.min()..=die.max()).contains(d)));
+        }
+    }
+}
+
+#[test]
+fn roll_with_mode() {
+    let mut rng = rand_utils::rng_from_entropy();
+    for die in all_dice() {
+        for mode in RollMode::iter() {
+            let RollResult {
+                kept, discarded, ..
+            } = die.roll_with_mode(&mut rng, mode);
+            assert_eq!(kept.len(), 1);
+            assert_eq!(discarded.len(), 1);
+            assert!([kept[0], discarded[0]]
+                .iter()
+                .all(|r| (die.min()..=die.max()).contains(r)));
+
+            match mode {
+                RollMode::Advantage => assert!(kept[0] >= discarded[0]),
+                RollMode::Disadvantage => assert!(kept[0] <= discarded[0]),
+            }
         }
     }
 }
@@ -52,8 +92,467 @@ fn roll_multiple() {
 fn roll_command() {
     let mut rng = rand_utils::rng_from_entropy();
     let amount = rng.gen::<u8>() as usize;
-    let die = Die::iter().choose(&mut rng).unwrap();
+    let die = all_dice().choose(&mut rng).unwrap();
     let rolls = Roll::new(amount, die).gen(&mut rng);
 
     assert_eq!(rolls.count(), amount);
 }
+
+#[test]
+fn parse_dice() {
+    for die in all_dice() {
+        assert_eq!(die.to_string().parse::<Die>(), Ok(die));
+        assert_eq!(
+            serde_json::from_value::<Die>(serde_json::to_value(die).unwrap()).unwrap(),
+            die
+        );
+    }
+
+    assert_eq!("d6".parse::<Die>(), Ok(Die::D6));
+    assert_eq!("D20".parse::<Die>(), Ok(Die::D20));
+    assert_eq!("d%".parse::<Die>(), Ok(Die::D100));
+    assert_eq!("df".parse::<Die>(), Ok(Die::Fudge));
+    assert_eq!("d3".parse::<Die>(), Ok(Die::with_sides(3).unwrap()));
+    assert!("d0".parse::<Die>().is_err());
+    assert!("d256".parse::<Die>().is_err());
+    assert!("d128".parse::<Die>().is_err());
+    assert_eq!("d127".parse::<Die>().unwrap().sides(), 127);
+    assert!("6".parse::<Die>().is_err());
+}
+
+#[test]
+fn fudge_dice() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let expression = "4dF+1".parse::<Expression>().unwrap();
+
+    assert_eq!(expression.to_string(), "4dF+1");
+    assert_eq!(expression.min(), -3);
+    assert_eq!(expression.max(), 5);
+
+    let rolls = Roll::new(100, Die::Fudge).roll(&mut rng);
+    for face in -1..=1 {
+        assert!(rolls.kept.contains(&face));
+    }
+}
+
+#[test]
+fn roll_with_selectors() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for amount in 0..=6usize {
+        for drop in 0..=amount + 1 {
+            let kept = amount.saturating_sub(drop);
+            let keep_highest = Roll::new(amount, Die::D6).keep_highest(kept);
+            let keep_lowest = Roll::new(amount, Die::D6).keep_lowest(kept);
+            let drop_highest = Roll::new(amount, Die::D6).drop_highest(drop);
+            let drop_lowest = Roll::new(amount, Die::D6).drop_lowest(drop);
+
+            for roll in [keep_highest, drop_lowest] {
+                assert_eq!(roll.min(), i32::try_from(kept).unwrap());
+                assert_eq!(roll.max(), i32::try_from(kept * 6).unwrap());
+
+                let RollResult {
+                    kept: k,
+                    discarded: d,
+                    ..
+                } = roll.roll(&mut rng);
+                assert_eq!(k.len(), kept);
+                assert_eq!(d.len(), amount - kept);
+                assert!(k.iter().all(|k| d.iter().all(|d| k >= d)));
+            }
+
+            for roll in [keep_lowest, drop_highest] {
+                assert_eq!(roll.min(), i32::try_from(kept).unwrap());
+                assert_eq!(roll.max(), i32::try_from(kept * 6).unwrap());
+
+                let RollResult {
+                    kept: k,
+                    discarded: d,
+                    ..
+                } = roll.roll(&mut rng);
+                assert_eq!(k.len(), kept);
+                assert_eq!(d.len(), amount - kept);
+                assert!(k.iter().all(|k| d.iter().all(|d| k <= d)));
+            }
+        }
+    }
+}
+
+#[test]
+fn exploding_dice() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let roll = Roll::new(100, Die::D4).explode();
+
+    assert_eq!(roll.min(), 100);
+    assert_eq!(roll.max(), 100 * 4 * (1 + i32::from(MAX_EXPLOSIONS)));
+
+    let result = roll.roll(&mut rng);
+    assert!(result.dice.iter().any(|d| d.rolls.len() > 1));
+    for DieResult { value, rolls } in result.dice {
+        assert_eq!(value, rolls.iter().sum::<i16>());
+        assert!(rolls.len() <= 1 + usize::from(MAX_EXPLOSIONS));
+        // Every roll but the last hit the max and exploded
+        let (last, exploded) = rolls.split_last().unwrap();
+        assert!(exploded.iter().all(|&r| r == 4));
+        assert!(*last < 4 || rolls.len() == 1 + usize::from(MAX_EXPLOSIONS));
+    }
+
+    // A die that can only roll its max stops at the cap
+    let result = Roll::new(1, Die::with_sides(1).unwrap())
+        .explode()
+        .roll(&mut rng);
+    assert_eq!(result.dice[0].rolls.len(), 1 + usize::from(MAX_EXPLOSIONS));
+}
+
+#[test]
+fn rerolling_dice() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let result = Roll::new(100, Die::D6).reroll_below(3).roll(&mut rng);
+
+    assert!(result.dice.iter().any(|d| d.rolls.len() > 1));
+    for DieResult { value, rolls } in result.dice {
+        match rolls.as_slice() {
+            // Only rerolled once, even if the reroll is low
+            [first, second] => {
+                assert!(*first < 3);
+                assert_eq!(value, *second);
+            }
+            [first] => {
+                assert!(*first >= 3);
+                assert_eq!(value, *first);
+            }
+            _ => panic!("Rerolled more than once: {rolls:?}"),
+        }
+    }
+}
+
+#[test]
+fn minimum_dice() {
+    let mut rng = rand_utils::rng_from_entropy();
+    let roll = Roll::new(100, Die::D20).minimum(10);
+
+    assert_eq!(roll.min(), 1000);
+    assert_eq!(roll.max(), 2000);
+
+    let result = roll.roll(&mut rng);
+    for DieResult { value, rolls } in result.dice {
+        assert_eq!(value, rolls[0].max(10));
+    }
+}
+
+#[test]
+fn parse_modifiers() {
+    for (expression, roll) in [
+        ("3d6!", Roll::new(3, Die::D6).explode()),
+        ("2d6r<3", Roll::new(2, Die::D6).reroll_below(3)),
+        ("1d20m10", Roll::new(1, Die::D20).minimum(10)),
+        (
+            "4d6!r<2dl1",
+            Roll::new(4, Die::D6)
+                .explode()
+                .reroll_below(2)
+                .drop_lowest(1),
+        ),
+    ] {
+        let parsed = expression.parse::<Expression>().unwrap();
+        assert_eq!(
+            parsed.terms(),
+            &[Term::Dice {
+                sign: Sign::Positive,
+                roll
+            }]
+        );
+        assert_eq!(parsed.to_string(), expression);
+    }
+
+    assert_eq!(
+        "1d20m".parse::<Expression>(),
+        Err(ParseExpressionError::InvalidNumber(String::new()))
+    );
+    assert_eq!(
+        "1d20!2".parse::<Expression>(),
+        Err(ParseExpressionError::InvalidTerm("!2".to_owned()))
+    );
+}
+
+#[test]
+fn parse_expression() {
+    let expression = "2d6 + 1d4 - 2d8kh1 - 3".parse::<Expression>().unwrap();
+
+    assert_eq!(
+        expression.terms(),
+        &[
+            Term::Dice {
+                sign: Sign::Positive,
+                roll: Roll::new(2, Die::D6),
+            },
+            Term::Dice {
+                sign: Sign::Positive,
+                roll: Roll::new(1, Die::D4),
+            },
+            Term::Dice {
+                sign: Sign::Negative,
+                roll: Roll::new(2, Die::D8).keep_highest(1),
+            },
+            Term::Modifier(-3),
+        ]
+    );
+    assert_eq!(expression.to_string(), "2d6+1d4-2d8kh1-3");
+}
+
+#[test]
+fn parse_invalid_expressions() {
+    assert_eq!("".parse::<Expression>(), Err(ParseExpressionError::Empty));
+    assert_eq!(
+        "2d0".parse::<Expression>(),
+        Err(ParseExpressionError::UnsupportedDie("0".to_owned()))
+    );
+    assert_eq!(
+        "xd6".parse::<Expression>(),
+        Err(ParseExpressionError::InvalidNumber("x".to_owned()))
+    );
+    assert_eq!(
+        "2d6++3".parse::<Expression>(),
+        Err(ParseExpressionError::InvalidTerm("2d6++3".to_owned()))
+    );
+    assert_eq!(
+        "100000d6".parse::<Expression>(),
+        Err(ParseExpressionError::TooManyDice(100_000))
+    );
+    assert_eq!(
+        "1d20 5".parse::<Expression>(),
+        Err(ParseExpressionError::MissingSign("1d20 5".to_owned()))
+    );
+    assert_eq!(
+        "2d6 3".parse::<Expression>(),
+        Err(ParseExpressionError::MissingSign("2d6 3".to_owned()))
+    );
+    assert_eq!(
+        "2147483647+2147483647".parse::<Expression>(),
+        Err(ParseExpressionError::OutOfRange(
+            "2147483647+2147483647".to_owned()
+        ))
+    );
+    assert_eq!(
+        "-2147483647-1d6".parse::<Expression>(),
+        Err(ParseExpressionError::OutOfRange(
+            "-2147483647-1d6".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn new_expressions_are_validated() {
+    let expression = Expression::new(vec![Term::Modifier(i32::MIN)]).unwrap();
+    assert_eq!(expression.to_string(), "-2147483648");
+
+    assert_eq!(
+        Expression::new(vec![Term::Dice {
+            sign: Sign::Positive,
+            roll: Roll::new(100_000, Die::D6),
+        }]),
+        Err(ParseExpressionError::TooManyDice(100_000))
+    );
+    assert_eq!(
+        Expression::new(vec![Term::Modifier(i32::MIN), Term::Modifier(-1)]),
+        Err(ParseExpressionError::OutOfRange("-2147483648-1".to_owned()))
+    );
+}
+
+#[test]
+fn roll_expression() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for expression in [
+        "1d20-2",
+        "2d6+1d4+3",
+        "4d6kh3",
+        "2d20kl1+5",
+        "-1d4",
+        "4d6d1",
+        "3d8dh2",
+    ] {
+        let expression = expression.parse::<Expression>().unwrap();
+        for _ in 0..100 {
+            let result = expression.roll(&mut rng);
+
+            assert!((expression.min()..=expression.max()).contains(&result.total));
+            assert_eq!(result.terms.len(), expression.terms().len());
+            assert_eq!(
+                result.total,
+                result.terms.iter().map(|t| t.total).sum::<i32>()
+            );
+        }
+    }
+}
+
+/// Adds up a sorted combination of faces the same way a selector would
+type Total = fn(&[i16]) -> i16;
+
+/// Exact probability of each total, found by going through every possible
+/// combination of faces. Each combination is sorted from lowest to highest
+/// before being totalled.
+fn brute_force(amount: usize, die: Die, total: Total) -> HashMap<i32, f64> {
+    let combinations = (0..amount)
+        .map(|_| die.min()..=die.max())
+        .multi_cartesian_product()
+        .collect::<Vec<_>>();
+    let chance = 1.0 / f64::from(u32::try_from(combinations.len()).unwrap());
+
+    let mut probabilities = HashMap::new();
+    for mut faces in combinations {
+        faces.sort_unstable();
+        *probabilities.entry(total(&faces).into()).or_default() += chance;
+    }
+    probabilities
+}
+
+#[test]
+fn distribution_of_selected_dice() {
+    let cases: [(Roll, usize, Die, Total); 7] = [
+        (Roll::new(3, Die::D6), 3, Die::D6, |f| f.iter().sum()),
+        (Roll::new(4, Die::D6).drop_lowest(1), 4, Die::D6, |f| {
+            f[1..].iter().sum()
+        }),
+        (Roll::new(3, Die::D4).keep_highest(2), 3, Die::D4, |f| {
+            f[1..].iter().sum()
+        }),
+        (Roll::new(3, Die::D8).keep_lowest(1), 3, Die::D8, |f| f[0]),
+        (Roll::new(3, Die::D6).drop_highest(2), 3, Die::D6, |f| f[0]),
+        (
+            Roll::new(2, Die::Fudge).keep_highest(1),
+            2,
+            Die::Fudge,
+            |f| f[1],
+        ),
+        (
+            Roll::with_mode(Die::D20, RollMode::Disadvantage),
+            2,
+            Die::D20,
+            |f| f[0],
+        ),
+    ];
+
+    for (roll, amount, die, total) in cases {
+        let distribution = roll.distribution().unwrap();
+        let expected = brute_force(amount, die, total);
+
+        assert_eq!(distribution.min(), roll.min());
+        assert_eq!(distribution.max(), roll.max());
+        assert_eq!(distribution.probabilities().count(), expected.len());
+        for (value, probability) in distribution.probabilities() {
+            assert!(
+                (probability - expected[&value]).abs() < 1e-9,
+                "{roll}: {value}"
+            );
+        }
+        let total = distribution.probabilities().map(|(_, p)| p).sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn distribution_with_modifiers() {
+    // Great Weapon Fighting: 1s and 2s are rerolled once
+    let distribution = Roll::new(1, Die::D6)
+        .reroll_below(3)
+        .distribution()
+        .unwrap();
+    assert!((distribution.probability(1) - 2.0 / 36.0).abs() < 1e-9);
+    assert!((distribution.probability(6) - 8.0 / 36.0).abs() < 1e-9);
+
+    // Reliable Talent: anything below 10 becomes a 10
+    let distribution = Roll::new(1, Die::D20).minimum(10).distribution().unwrap();
+    assert!((distribution.probability(10) - 0.5).abs() < 1e-9);
+    assert!(distribution.probability(9) < 1e-9);
+
+    // Exploding dice can never total a multiple of the max by itself
+    let distribution = Roll::new(1, Die::D4).explode().distribution().unwrap();
+    assert!(distribution.probability(4) < 1e-9);
+    assert!((distribution.probability(5) - 1.0 / 16.0).abs() < 1e-9);
+    assert_eq!(distribution.max(), Roll::new(1, Die::D4).explode().max());
+    assert!((distribution.mean() - 2.5 * 4.0 / 3.0).abs() < 1e-4);
+
+    // Sampled results should line up with the exact mean
+    let mut rng = rand_utils::rng_from_entropy();
+    for roll in [
+        Roll::new(4, Die::D6).explode().drop_lowest(1),
+        Roll::new(2, Die::D8).reroll_below(3).minimum(4),
+        Roll::new(3, Die::Fudge).explode(),
+    ] {
+        let distribution = roll.distribution().unwrap();
+        let mean = (0..2000)
+            .map(|_| f64::from(roll.roll(&mut rng).total()))
+            .mean();
+        assert!((mean - distribution.mean()).abs() < distribution.std_dev() / 5.0);
+    }
+}
+
+#[test]
+fn distribution_of_expression() {
+    let distribution = "1d20+5-1d4"
+        .parse::<Expression>()
+        .unwrap()
+        .distribution()
+        .unwrap();
+
+    assert_eq!(distribution.min(), 2);
+    assert_eq!(distribution.max(), 24);
+    assert!((distribution.mean() - 13.0).abs() < 1e-9);
+    assert!((distribution.variance() - (399.0 + 15.0) / 12.0).abs() < 1e-9);
+    assert_eq!(distribution.percentile(0.0), 2);
+    assert_eq!(distribution.percentile(50.0), 13);
+    assert_eq!(distribution.percentile(100.0), 24);
+
+    assert_eq!(
+        Roll::new(1000, Die::D100).distribution(),
+        Err(DistributionError::TooComplex("1000d100".to_owned()))
+    );
+}
+
+#[test]
+fn roll_pool() {
+    let mut rng = rand_utils::rng_from_entropy();
+
+    for pool in [
+        Pool::new(5, Die::D10, 6),
+        Pool::new(10, Die::D10, 8).subtract_ones(),
+        Pool::new(3, Die::D10, 10).botch(),
+        Pool::new(6, Die::D6, 5).subtract_ones().botch(),
+    ] {
+        for _ in 0..100 {
+            let result = pool.roll(&mut rng);
+            assert_eq!(result.rolls.len(), pool.amount());
+            assert_eq!(result.successes + result.failures, pool.amount());
+            assert_eq!(
+                result.ones,
+                result.rolls.iter().filter(|&&r| r == 1).count()
+            );
+            assert!(result.net_successes <= result.successes);
+
+            match result.outcome {
+                PoolOutcome::Success => assert!(result.net_successes > 0),
+                PoolOutcome::Failure => assert_eq!(result.net_successes, 0),
+                PoolOutcome::Botch => {
+                    assert_eq!(result.successes, 0);
+                    assert!(result.ones > 0);
+                }
+            }
+        }
+    }
+
+    // Ones cancel out successes, but never go below zero
+    let result = Pool::new(20, Die::D10, 2).subtract_ones().roll(&mut rng);
+    assert_eq!(
+        result.net_successes,
+        result.successes.saturating_sub(result.ones)
+    );
+
+    // Without the botch rule, a roll with no successes is just a failure
+    let result = Pool::new(20, Die::D10, 11).roll(&mut rng);
+    assert_eq!(result.successes, 0);
+    assert_eq!(result.outcome, PoolOutcome::Failure);
+
+    let result = Pool::new(200, Die::D10, 11).botch().roll(&mut rng);
+    assert_eq!(result.outcome, PoolOutcome::Botch);
+}
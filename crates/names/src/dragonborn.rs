@@ -12,6 +12,7 @@ use crate::NameGenerator;
 /// names first as a mark of honor. A childhood name or nickname is often used
 /// among clutchmates as a descriptive term or a term of endearment. The name
 /// might recall an event or center on a habit.
+#[allow(clippy::struct_field_names)]
 #[derive(Debug)]
 pub struct Dragonborn {
     /// Personal name given at birth
@@ -21,6 +21,7 @@ use crate::NameGenerator;
 /// combination of other Elvish words. Some elves traveling among humans
 /// translate their family names into Common, but others retain the Elvish
 /// version.
+#[allow(clippy::struct_field_names)]
 #[derive(Debug)]
 pub struct Elf {
     /// Childhood name
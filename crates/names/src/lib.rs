@@ -12,6 +12,8 @@
     rust_2021_compatibility,
     unused
 )]
+// Name lists are kept at the bottom of each module, after their tests
+#![allow(clippy::items_after_test_module)]
 
 use std::fmt;
 
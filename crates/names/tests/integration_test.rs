@@ -1,3 +1,4 @@
+//! Integration tests for the `names` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -10,7 +11,10 @@
     unused
 )]
 
+use std::collections::BTreeMap;
+
 use names::Name;
+use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
 use strum::IntoEnumIterator;
 
 #[test]
@@ -20,3 +24,22 @@ fn can_generate_all_names() {
         assert!(!name.gen(&mut rng).is_empty());
     }
 }
+
+#[test]
+fn seeded_names_are_stable() {
+    // No version has changed how names are generated yet
+    assert_golden_versions(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"),
+        &[GeneratorVersion::V1],
+        |version| {
+            Name::iter()
+                .map(|name| {
+                    let generated = (0..3)
+                        .map(|seed| name.gen(&mut version.rng_from_seed(seed)))
+                        .collect::<Vec<_>>();
+                    (name.to_string(), generated)
+                })
+                .collect::<BTreeMap<_, _>>()
+        },
+    );
+}
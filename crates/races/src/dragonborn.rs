@@ -1,6 +1,7 @@
 use std::{borrow::Cow, fmt, ops::RangeInclusive};
 
-use deities::{Deities, Pantheon};
+use abilities::Ability;
+use deities::{Deities, Pantheon, PatronKind};
 use descriptions::{Appearance, Backstory};
 use names::Name;
 use rand::{
@@ -52,6 +53,12 @@ impl Deities for Dragonborn {
     fn pantheons(&self) -> Cow<'_, [Pantheon]> {
         Cow::Borrowed(&[Pantheon::Dragon])
     }
+
+    /// Having long lived without gods, many dragonborn put their faith in
+    /// ideals, their clan and themselves instead.
+    fn patron_kinds(&self) -> Cow<'_, [PatronKind]> {
+        Cow::Borrowed(&[PatronKind::Philosophy])
+    }
 }
 
 impl RaceGenerator for Dragonborn {
@@ -65,6 +72,11 @@ impl RaceGenerator for Dragonborn {
         &[2, 1]
     }
 
+    /// Ability score increases from the Player's Handbook
+    fn fixed_ability_increases(&self) -> &[(Ability, u8)] {
+        &[(Ability::Strength, 2), (Ability::Charisma, 1)]
+    }
+
     /// Generate an age for a character of this race
     fn age_range(&self) -> RangeInclusive<u16> {
         3..=80
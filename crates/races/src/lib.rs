@@ -15,7 +15,8 @@
 
 use std::{borrow::Cow, fmt, ops::RangeInclusive};
 
-use deities::{Deities, Pantheon};
+use abilities::Ability;
+use deities::{Deities, Pantheon, PatronKind};
 use descriptions::{Appearance, Backstory};
 use enum_dispatch::enum_dispatch;
 use names::Name;
@@ -49,9 +50,18 @@ where
         self.name_generator().gen(rng)
     }
 
-    /// Ability increases available for this race
+    /// Ability increases available for this race, which can be assigned to
+    /// any abilities with [`abilities::RacialIncreaseRule::Floating`]
     fn ability_increases(&self) -> &[u8];
 
+    /// Abilities this race's increases are assigned to with
+    /// [`abilities::RacialIncreaseRule::Fixed`]. Should be the same sizes as
+    /// [`RaceGenerator::ability_increases`]. Races without fixed increases
+    /// use their floating increases instead.
+    fn fixed_ability_increases(&self) -> &[(Ability, u8)] {
+        &[]
+    }
+
     /// Range of ages applicable for this race
     fn age_range(&self) -> RangeInclusive<u16>;
 
@@ -143,6 +153,12 @@ impl Deities for Race {
             Self::Dragonborn(d) => d.deity_required(),
         }
     }
+
+    fn patron_kinds(&self) -> Cow<'_, [PatronKind]> {
+        match self {
+            Self::Dragonborn(d) => d.patron_kinds(),
+        }
+    }
 }
 
 impl Sources for Race {
@@ -1,3 +1,4 @@
+//! Integration tests for the `races` crate
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -10,6 +11,7 @@
     unused
 )]
 
+use itertools::Itertools;
 use races::{Race, RaceGenerator, RaceOption};
 use rand::Rng;
 use sizes::HeightAndWeight;
@@ -37,6 +39,21 @@ fn can_generate_all_races() {
         assert!(!sources.is_empty());
 
         assert!(!race.ability_increases().is_empty());
+        // Fixed increases are the same sizes as floating ones, for different abilities
+        let mut fixed = race
+            .fixed_ability_increases()
+            .iter()
+            .map(|&(_, increase)| increase)
+            .collect::<Vec<_>>();
+        let mut floating = race.ability_increases().to_vec();
+        fixed.sort_unstable();
+        floating.sort_unstable();
+        assert_eq!(fixed, floating);
+        assert!(race
+            .fixed_ability_increases()
+            .iter()
+            .map(|&(ability, _)| ability)
+            .all_unique());
 
         let citation = race.citation();
         assert!(citation.contains(&race.to_string()));
@@ -0,0 +1,111 @@
+//! Golden-file tests for seeded output.
+//!
+//! Generated output for a pinned seed and [`GeneratorVersion`](crate::GeneratorVersion)
+//! is compared against a file checked into the repository, so that any change
+//! that would break a saved seed is caught.
+
+use std::{fs, path::Path};
+
+use serde::Serialize;
+use serde_json::Value;
+use strum::IntoEnumIterator;
+
+use crate::GeneratorVersion;
+
+/// Environment variable that rewrites golden files instead of comparing
+/// against them
+const UPDATE_VAR: &str = "UPDATE_GOLDEN";
+
+/// Compare a value against the JSON stored in a golden file.
+///
+/// Run the tests with `UPDATE_GOLDEN=1` to write the current output to the
+/// file instead, after adding a new generator version.
+///
+/// # Panics
+///
+/// Panics if the value doesn't match the golden file, or if the file can't be
+/// read or written.
+pub fn assert_golden(path: impl AsRef<Path>, actual: &impl Serialize) {
+    let path = path.as_ref();
+    let actual = serde_json::to_value(actual).expect("value should serialize to JSON");
+
+    if std::env::var_os(UPDATE_VAR).is_some() {
+        let contents = serde_json::to_string_pretty(&actual).expect("JSON should serialize");
+        fs::write(path, contents + "\n").expect("golden file should be writable");
+    }
+
+    compare(path, &actual);
+}
+
+/// Compare the output of every [`GeneratorVersion`] against the golden files
+/// in a directory.
+///
+/// Only the versions listed in `changes` have their own file, named after the
+/// version, such as `v1.json`. Every other version is compared against the
+/// file of the most recent change before it, which checks that versions meant
+/// to leave this output alone really do.
+///
+/// ```no_run
+/// use rand::Rng;
+/// use rand_utils::{golden::assert_golden_versions, GeneratorVersion};
+///
+/// assert_golden_versions("tests/golden", &[GeneratorVersion::V1], |version| {
+///     version.rng_from_seed(42).gen::<u32>()
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics if `changes` doesn't start with the first version, if the output of
+/// any version doesn't match its golden file, or if a version listed in
+/// `changes` produces the same output as the version before it.
+pub fn assert_golden_versions<T: Serialize>(
+    dir: impl AsRef<Path>,
+    changes: &[GeneratorVersion],
+    generate: impl Fn(GeneratorVersion) -> T,
+) {
+    let dir = dir.as_ref();
+    assert_eq!(
+        changes.first(),
+        Some(&GeneratorVersion::V1),
+        "the first version should always have a golden file"
+    );
+
+    let mut previous: Option<Value> = None;
+    for version in GeneratorVersion::iter() {
+        let actual =
+            serde_json::to_value(generate(version)).expect("value should serialize to JSON");
+        let change = changes
+            .iter()
+            .rev()
+            .find(|change| **change <= version)
+            .expect("the first version is always a change");
+
+        if *change == version {
+            assert!(
+                previous.as_ref() != Some(&actual),
+                "{version} is listed as a change, but its output is the same as the version before it"
+            );
+            assert_golden(dir.join(format!("{version}.json")), &actual);
+        } else {
+            compare(&dir.join(format!("{change}.json")), &actual);
+        }
+
+        previous = Some(actual);
+    }
+}
+
+/// Compare JSON against the contents of a golden file
+fn compare(path: &Path, actual: &Value) {
+    let expected: Value = serde_json::from_str(
+        &fs::read_to_string(path).expect("golden file should exist. Run with UPDATE_GOLDEN=1"),
+    )
+    .expect("golden file should be valid JSON");
+
+    assert_eq!(
+        actual,
+        &expected,
+        "Output for a pinned seed no longer matches {}. If this change is intentional, add a new GeneratorVersion that keeps the old behavior, then run with {UPDATE_VAR}=1.",
+        path.display()
+    );
+}
@@ -27,6 +27,11 @@ use rand::{
     Rng, SeedableRng,
 };
 use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+#[cfg(feature = "golden")]
+pub mod golden;
 
 /// Creates a new instance of the RNG seeded via getrandom.
 /// Consolidates choice of random number generator algorithm to a single place.
@@ -47,6 +52,84 @@ pub fn rng_from_entropy() -> impl Rng {
     Pcg64::from_entropy()
 }
 
+/// Creates a new instance of the RNG from a given seed, so that the same
+/// values can be generated again later by reusing the seed.
+/// Uses the [`GeneratorVersion::LATEST`] version of the generator.
+///
+/// ```
+/// use rand::Rng;
+///
+/// let x: u32 = rand_utils::rng_from_seed(42).gen();
+/// let y: u32 = rand_utils::rng_from_seed(42).gen();
+/// assert_eq!(x, y);
+/// ```
+#[tracing::instrument]
+#[must_use]
+pub fn rng_from_seed(seed: u64) -> impl Rng {
+    GeneratorVersion::LATEST.rng_from_seed(seed)
+}
+
+/// Version of the generation logic. A seed only reproduces the same output
+/// when paired with the same version.
+///
+/// Whenever a change would alter the output for an existing seed, such as
+/// editing a name list or changing the order values are generated in, a new
+/// version should be added and the old behavior kept available under the
+/// old version.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum GeneratorVersion {
+    /// Initial version of the generators
+    V1,
+    /// Adds the Order, Peace, and Twilight cleric domains to random domain
+    /// choices, and reports the combat role ability scores were assigned for,
+    /// along with character levels, Ability Score Improvements, and
+    /// proficiency, skill, and saving throw bonuses
+    V2,
+    /// Adds lore about deities, and makes characters less likely to favor
+    /// deities who are rivals of their race's pantheons
+    V3,
+    /// Characters may serve a patron or philosophy instead of a deity
+    V4,
+}
+
+impl GeneratorVersion {
+    /// Most recent version, used when a version isn't specified
+    pub const LATEST: Self = Self::V4;
+
+    /// Creates a new instance of the RNG used by this version from a given
+    /// seed.
+    ///
+    /// ```
+    /// use rand::Rng;
+    /// use rand_utils::GeneratorVersion;
+    ///
+    /// let x: u32 = GeneratorVersion::V1.rng_from_seed(42).gen();
+    /// let y: u32 = GeneratorVersion::V1.rng_from_seed(42).gen();
+    /// assert_eq!(x, y);
+    /// ```
+    #[must_use]
+    pub fn rng_from_seed(self, seed: u64) -> impl Rng {
+        match self {
+            Self::V1 | Self::V2 | Self::V3 | Self::V4 => Pcg64::seed_from_u64(seed),
+        }
+    }
+}
+
 /// Create an exponential based weight from a value.
 /// Works well for `choose_exp_weighted*` options.
 fn exp_weight<V>(val: V) -> f64
@@ -56,10 +139,36 @@ where
     E.powi(i32::try_from(val).unwrap_or_default())
 }
 
+/// Probability of each weight being chosen by `choose_exp_weighted`, in the
+/// same order as the weights.
+///
+/// ```
+/// let probabilities = rand_utils::exp_probabilities(&[1, 1, 0]);
+///
+/// assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+/// assert!((probabilities[0] - probabilities[1]).abs() < 1e-9);
+/// assert!(probabilities[0] > probabilities[2]);
+/// ```
+#[must_use]
+pub fn exp_probabilities(weights: &[i32]) -> Vec<f64> {
+    let min = weights.iter().copied().min().unwrap_or_default();
+    let weights = weights
+        .iter()
+        .map(|&w| exp_weight(w - min))
+        .collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+    weights.into_iter().map(|w| w / total).collect()
+}
+
+/// How much to lower the weight of a choice made with
+/// [`SliceExpRandom::choose_exp_weighted`] to make it unlikely, without ruling
+/// it out. Makes the choice `E.pow(2)` times, or about 7 times, less likely.
+pub const UNLIKELY_PENALTY: i32 = 2;
+
 /// Choose values from a slice based on exponential weights.
 /// Will adjust weights by offset of the minimum score so that the minimum
 /// weight is always 1.
-
+///
 /// Extension trait on slices, providing exponential based sampling.
 ///
 /// This trait is implemented on all `[T]` slice types, providing several
@@ -114,12 +223,12 @@ pub trait SliceExpRandom: SliceRandom {
     ///
     /// ```
     /// use rand_utils::SliceExpRandom;
-
+    ///
     /// let choices = [('a', 2), ('b', 1), ('c', 1)];
     /// let mut rng = rand_utils::rng_from_entropy();
     /// println!("{:?}", choices.choose_multiple_exp_weighted(&mut rng, 2, |item| item.1).unwrap().collect::<Vec<_>>());
     /// ```
-
+    ///
     /// # Errors
     ///
     /// Errors if weights supplied are not valid
@@ -182,4 +291,13 @@ mod test {
         let x: u8 = rng.gen();
         assert!((0..255).contains(&x));
     }
+
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let x: [u64; 8] = rng_from_seed(1).gen();
+        let y: [u64; 8] = rng_from_seed(1).gen();
+        let z: [u64; 8] = rng_from_seed(2).gen();
+        assert_eq!(x, y);
+        assert_ne!(x, z);
+    }
 }
@@ -104,14 +104,18 @@ impl HeightAndWeightTable {
     ///
     /// let HeightAndWeight { height, weight } = HeightAndWeightTable::Dragonborn.gen(&mut rand::thread_rng());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Will only panic if a table uses dice that can roll negative values
     #[tracing::instrument(skip(rng))]
     pub fn gen<R: Rng + ?Sized>(self, rng: &mut R) -> HeightAndWeight {
-        let height_mod = self.height_modifier().gen(rng).sum::<u8>();
+        let height_mod = u8::try_from(self.height_modifier().roll(rng).total()).unwrap();
 
         // Weight modifier is multiplied by height
         let weight_mod: u16 = match self.weight_modifier() {
             WeightMod::_Fixed(f) => f,
-            WeightMod::Roll(r) => u16::from(r.gen(rng).sum::<u8>()),
+            WeightMod::Roll(r) => u16::try_from(r.roll(rng).total()).unwrap(),
         } * u16::from(height_mod);
 
         HeightAndWeight {
@@ -141,7 +145,7 @@ mod test {
         fn min(self) -> usize {
             match self {
                 Self::_Fixed(f) => usize::from(f),
-                Self::Roll(r) => r.min(),
+                Self::Roll(r) => usize::try_from(r.min()).unwrap(),
             }
         }
 
@@ -149,7 +153,7 @@ mod test {
         pub fn max(self) -> usize {
             match self {
                 Self::_Fixed(f) => usize::from(f),
-                Self::Roll(r) => r.max(),
+                Self::Roll(r) => usize::try_from(r.max()).unwrap(),
             }
         }
     }
@@ -164,11 +168,14 @@ mod test {
 
             let HeightAndWeight { height, weight } = table.gen(&mut rand_utils::rng_from_entropy());
 
-            let min_h = base_h + h_mod.min();
-            let max_h = base_h + h_mod.max();
+            let h_mod_min = usize::try_from(h_mod.min()).unwrap();
+            let h_mod_max = usize::try_from(h_mod.max()).unwrap();
+
+            let min_h = base_h + h_mod_min;
+            let max_h = base_h + h_mod_max;
 
-            let min_w = base_w + (h_mod.min() * w_mod.min());
-            let max_w = base_w + (h_mod.max() * w_mod.max());
+            let min_w = base_w + (h_mod_min * w_mod.min());
+            let max_w = base_w + (h_mod_max * w_mod.max());
 
             assert!((min_h..=max_h).contains(&height.into()));
             assert!((min_w..=max_w).contains(&weight.into()));
@@ -1,16 +1,42 @@
-use abilities::AbilityScores;
+use abilities::{Ability, AbilityScores, CombatRole, GenerationMethod, Skill};
 use axum::{response::IntoResponse, Json, Router};
-use axum_extra::routing::Resource;
-use rand::Rng;
+use axum_extra::{extract::Query, routing::Resource};
+use serde::Deserialize;
+
+use crate::seed::Seed;
 
 /// Routes related to abilities
 pub fn routes() -> Router {
     Router::from(Resource::named("abilities").create(create))
 }
 
+/// Options for generating ability scores, such as
+/// `?method=point-buy&skill=arcana&expertise=stealth&saving_throw=DEX`
+#[derive(Debug, Deserialize)]
+struct AbilityOptions {
+    #[serde(default)]
+    method: GenerationMethod,
+    /// Combat role to assign the highest scores for. Random if not provided.
+    role: Option<CombatRole>,
+    /// Skills to be proficient in
+    #[serde(default)]
+    skill: Vec<Skill>,
+    /// Skills to have expertise in
+    #[serde(default)]
+    expertise: Vec<Skill>,
+    /// Saving throws to be proficient in
+    #[serde(default)]
+    saving_throw: Vec<Ability>,
+}
+
 /// Create new set of ability scores
 #[tracing::instrument]
-async fn create() -> impl IntoResponse {
-    let scores: AbilityScores = rand_utils::rng_from_entropy().gen();
-    Json(scores)
+async fn create(Query(query): Query<AbilityOptions>, seed: Seed) -> impl IntoResponse {
+    let mut rng = seed.rng();
+    let mut scores = match query.role {
+        Some(role) => AbilityScores::gen_with_role(&mut rng, query.method, role),
+        None => AbilityScores::gen_with_method(&mut rng, query.method),
+    };
+    scores.set_proficiencies(&query.skill, &query.expertise, &query.saving_throw);
+    (seed, Json(scores.for_version(seed.version())))
 }
@@ -1,15 +1,96 @@
-use alignments::Alignment;
-use axum::{response::IntoResponse, Json, Router};
-use axum_extra::routing::Resource;
+use std::{borrow::Cow, collections::BTreeMap};
+
+use alignments::{Alignment, AlignmentInfluences, AlignmentOption, Attitude, Morality};
+use axum::{response::IntoResponse, routing::get, Json, Router};
+use axum_extra::{extract::Query, routing::Resource};
+use serde::Deserialize;
+
+use crate::seed::Seed;
 
 /// Routes related to alignments
 pub fn routes() -> Router {
     Router::from(Resource::named("alignments").create(create))
+        .route("/alignments/probabilities", get(probabilities))
+}
+
+/// Alignment influences passed in as query parameters. Each can be repeated,
+/// such as `?attitude=Lawful&morality=Good&morality=N&alignment=CG`.
+/// Alignments influence both attitude and morality.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct AlignmentQuery {
+    /// Attitude influences
+    attitude: Vec<Attitude>,
+    /// Morality influences
+    morality: Vec<Morality>,
+    /// Alignments, which influence both attitude and morality
+    alignment: Vec<Alignment>,
+}
+
+impl AlignmentInfluences for AlignmentQuery {
+    fn attitude(&self) -> Cow<'_, [Attitude]> {
+        self.attitude
+            .iter()
+            .copied()
+            .chain(
+                self.alignment
+                    .iter()
+                    .flat_map(|a| a.attitude().into_owned()),
+            )
+            .collect()
+    }
+
+    fn morality(&self) -> Cow<'_, [Morality]> {
+        self.morality
+            .iter()
+            .copied()
+            .chain(
+                self.alignment
+                    .iter()
+                    .flat_map(|a| a.morality().into_owned()),
+            )
+            .collect()
+    }
+}
+
+/// Alignments that are allowed to be generated, such as `?allowed=any
+/// non-good`. Any alignment if not provided.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct AlignmentFilters {
+    allowed: AlignmentOption,
+}
+
+/// Create new alignment, weighted by any influences
+#[tracing::instrument]
+async fn create(
+    Query(influences): Query<AlignmentQuery>,
+    Query(filters): Query<AlignmentFilters>,
+    seed: Seed,
+) -> impl IntoResponse {
+    let alignment = filters.allowed.gen(
+        &mut seed.rng(),
+        &influences.attitude(),
+        &influences.morality(),
+    );
+    (
+        seed,
+        Json(alignment.map_or_else(|| filters.allowed.to_string(), |a| a.to_string())),
+    )
 }
 
-/// Create new alignment
+/// Probability of generating each alignment with the given influences
 #[tracing::instrument]
-async fn create() -> impl IntoResponse {
-    let alignment = Alignment::gen(&mut rand_utils::rng_from_entropy(), &[], &[]);
-    Json(alignment.to_string())
+async fn probabilities(
+    Query(influences): Query<AlignmentQuery>,
+    Query(filters): Query<AlignmentFilters>,
+) -> impl IntoResponse {
+    Json(
+        filters
+            .allowed
+            .probabilities(&influences.attitude(), &influences.morality())
+            .into_iter()
+            .map(|(alignment, probability)| (alignment.to_string(), probability))
+            .collect::<BTreeMap<_, _>>(),
+    )
 }
@@ -1,16 +1,49 @@
-use axum::{response::IntoResponse, Json, Router};
-use axum_extra::routing::Resource;
-use characters::Character;
-use rand::Rng;
+use abilities::AbilityScores;
+use axum::{
+    body::Bytes,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json, Router,
+};
+use axum_extra::{extract::Query, routing::Resource};
+use characters::{Character, CharacterOptions};
+use serde::Deserialize;
+
+use crate::seed::Seed;
 
 /// Routes related to characters
 pub fn routes() -> Router {
     Router::from(Resource::named("characters").create(create))
 }
 
-/// Create new set of ability scores
+/// Parts of the character that are already known, and shouldn't be generated
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CharacterInput {
+    /// Base ability scores, such as `{"STR": 15, "DEX": 14, ...}`
+    ability_scores: Option<AbilityScores>,
+}
+
+/// Create a new character. Parts of the character can be provided in an
+/// optional JSON body, and the rest will be generated.
 #[tracing::instrument]
-async fn create() -> impl IntoResponse {
-    let character: Character = rand_utils::rng_from_entropy().gen();
-    Json(character)
+async fn create(Query(mut options): Query<CharacterOptions>, seed: Seed, body: Bytes) -> Response {
+    let input = if body.is_empty() {
+        CharacterInput::default()
+    } else {
+        match serde_json::from_slice::<CharacterInput>(&body) {
+            Ok(input) => input,
+            Err(error) => {
+                return (StatusCode::UNPROCESSABLE_ENTITY, error.to_string()).into_response()
+            }
+        }
+    };
+    if input.ability_scores.is_some() {
+        options.ability_scores = input.ability_scores;
+    }
+
+    match Character::try_gen_with_options(&mut seed.rng(), seed.version(), &options) {
+        Ok(character) => (seed, Json(character)).into_response(),
+        Err(error) => (StatusCode::UNPROCESSABLE_ENTITY, error.to_string()).into_response(),
+    }
 }
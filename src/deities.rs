@@ -1,29 +1,138 @@
-use axum::{extract::Query, response::IntoResponse, Json, Router};
-use axum_extra::routing::Resource;
-use deities::{Deity, Domain};
-use rand::Rng;
+use alignments::{AlignmentInfluences, AlignmentOption};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use axum_extra::{
+    extract::Query,
+    routing::{Resource, RouterExt, TypedPath},
+};
+use deities::{Deity, DeityOptions, Domain, Pantheon, Patron, PatronKind};
 use serde::Deserialize;
 use strum::IntoEnumIterator;
 
+use crate::{alignments::AlignmentQuery, seed::Seed};
+
 /// Routes related to deities
 pub fn routes() -> Router {
-    Router::from(Resource::named("deities").create(create_deity)).merge(Router::from(
+    Router::from(
+        Resource::named("deities")
+            .index(index_deity)
+            .create(create_deity),
+    )
+    .route("/deities/:name", get(show_deity))
+    .merge(Router::from(
         Resource::named("deities/domains")
             .index(index_domain)
             .create(create_domain),
     ))
+    .merge(Router::from(
+        Resource::named("deities/pantheons").index(index_pantheon),
+    ))
+    .typed_get(show_pantheon)
+    .merge(Router::from(
+        Resource::named("deities/patrons")
+            .index(index_patron)
+            .create(create_patron),
+    ))
 }
 
+/// Options for generating a deity, such as
+/// `?domain=Life&pantheon=Greek&pantheon=Norse&required=false`. Pantheons make
+/// a deity from them more likely, but don't rule out other pantheons.
 #[derive(Debug, Deserialize)]
-struct DeityFilters {
+struct DeityQuery {
+    domain: Option<Domain>,
+    #[serde(default)]
+    pantheon: Vec<Pantheon>,
+    /// Whether a deity has to be chosen. If not, the response may be `null`.
+    #[serde(default = "required_default")]
+    required: bool,
+}
+
+/// Deities are required unless otherwise specified
+fn required_default() -> bool {
+    true
+}
+
+/// Filters for listing deities, such as
+/// `?domain=Life&alignment=any%20good&pantheon=Greek&pantheon=Norse`.
+/// Deities from every pantheon are included if no pantheon is given.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DeityListFilters {
     domain: Option<Domain>,
+    alignment: AlignmentOption,
+    pantheon: Vec<Pantheon>,
+}
+
+impl DeityListFilters {
+    /// Whether a deity matches all of the filters
+    fn matches(&self, deity: &Deity) -> bool {
+        self.domain.map_or(true, |d| deity.domains.contains(&d))
+            && self.alignment.allows(deity.alignment)
+            && (self.pantheon.is_empty() || self.pantheon.contains(&deity.pantheon))
+    }
 }
 
-/// Choose a random deity
+/// List all deities matching the given filters
 #[tracing::instrument]
-async fn create_deity(Query(query): Query<DeityFilters>) -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(Deity::gen(&mut rng, query.domain, &[], &[], &[], true))
+async fn index_deity(Query(filters): Query<DeityListFilters>) -> impl IntoResponse {
+    Json(
+        Deity::iter()
+            .filter(|d| filters.matches(d))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Choose a random deity, weighted by any pantheon and alignment influences
+#[tracing::instrument]
+async fn create_deity(
+    Query(query): Query<DeityQuery>,
+    Query(influences): Query<AlignmentQuery>,
+    seed: Seed,
+) -> Response {
+    if let Some(domain) = query.domain.filter(|d| !d.is_in_version(seed.version())) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "The {domain} domain isn't available in generator version {}. Please choose another domain or a later version.",
+                seed.version()
+            ),
+        )
+            .into_response();
+    }
+
+    let mut rng = seed.rng();
+    match Deity::gen(
+        &mut rng,
+        &DeityOptions {
+            domain: query.domain,
+            likely_pantheons: &query.pantheon,
+            attitude_influences: &influences.attitude(),
+            morality_influences: &influences.morality(),
+            required: query.required,
+            ..DeityOptions::default()
+        },
+    ) {
+        Ok(deity) => (seed, Json(deity.map(|d| d.for_version(seed.version())))).into_response(),
+        Err(error) => (StatusCode::UNPROCESSABLE_ENTITY, error.to_string()).into_response(),
+    }
+}
+
+/// Look up a deity by name. Returns every deity with that name, since some
+/// are worshipped in more than one pantheon.
+#[tracing::instrument]
+async fn show_deity(Path(name): Path<String>) -> Response {
+    let deities = Deity::named(&name).collect::<Vec<_>>();
+    if deities.is_empty() {
+        (StatusCode::NOT_FOUND, format!("No deity named {name}")).into_response()
+    } else {
+        Json(deities).into_response()
+    }
 }
 
 /// List domain options
@@ -34,7 +143,78 @@ async fn index_domain() -> impl IntoResponse {
 
 /// Choose a random domain
 #[tracing::instrument]
-async fn create_domain() -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(rng.gen::<Domain>())
+async fn create_domain(seed: Seed) -> impl IntoResponse {
+    let mut rng = seed.rng();
+    (seed, Json(Domain::gen(&mut rng, seed.version())))
+}
+
+/// List pantheon options
+#[tracing::instrument]
+async fn index_pantheon() -> impl IntoResponse {
+    Json(Pantheon::all().collect::<Vec<_>>())
+}
+
+#[derive(Debug, Deserialize, TypedPath)]
+#[typed_path("/deities/pantheons/:pantheon")]
+struct PantheonDeities {
+    pantheon: Pantheon,
+}
+
+/// List the deities in a pantheon
+#[tracing::instrument]
+async fn show_pantheon(path: PantheonDeities) -> impl IntoResponse {
+    Json(path.pantheon.deities(None).into_owned())
+}
+
+/// Filters for listing patrons, such as `?kind=Fiend&alignment=any%20lawful`.
+/// Patrons of every kind are included if no kind is given.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PatronListFilters {
+    kind: Vec<PatronKind>,
+    alignment: AlignmentOption,
+}
+
+impl PatronListFilters {
+    /// Whether a patron matches all of the filters
+    fn matches(&self, patron: &Patron) -> bool {
+        (self.kind.is_empty() || self.kind.contains(&patron.kind))
+            && self.alignment.allows(patron.alignment)
+    }
+}
+
+/// List all patrons and philosophies matching the given filters
+#[tracing::instrument]
+async fn index_patron(Query(filters): Query<PatronListFilters>) -> impl IntoResponse {
+    Json(
+        Patron::iter()
+            .filter(|p| filters.matches(p))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Options for generating a patron, such as `?kind=Archfey&kind=Fiend`. Kinds
+/// make a patron of that kind more likely, but don't rule out other kinds.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PatronOptions {
+    kind: Vec<PatronKind>,
+}
+
+/// Choose a random patron or philosophy, weighted by any kind and alignment
+/// influences
+#[tracing::instrument]
+async fn create_patron(
+    Query(options): Query<PatronOptions>,
+    Query(influences): Query<AlignmentQuery>,
+    seed: Seed,
+) -> impl IntoResponse {
+    let mut rng = seed.rng();
+    let patron = Patron::gen(
+        &mut rng,
+        &options.kind,
+        &influences.attitude(),
+        &influences.morality(),
+    );
+    (seed, Json(patron))
 }
@@ -1,15 +1,26 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use axum::{response::IntoResponse, routing::post, Json, Router};
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
 use axum_extra::routing::{RouterExt, TypedPath};
-use dice::Die;
-use serde::Deserialize;
+use dice::{Die, Expression, ParseExpressionError, Pool, RollMode, RollResult, MAX_DICE};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::seed::UnversionedSeed;
 
 /// Routes related to dice
 pub fn routes() -> Router {
     Router::new()
         .typed_post(roll)
         .route("/roll", post(roll_multiple))
+        .route("/pool", post(roll_pool))
+        .route("/stats", get(stats))
 }
 
 #[derive(Debug, Deserialize, TypedPath)]
@@ -18,21 +29,151 @@ struct DieRoll {
     die: Die,
 }
 
-/// Roll a given type and amount of dice
+#[derive(Debug, Deserialize)]
+struct DieRollOptions {
+    mode: Option<RollMode>,
+}
+
+/// Result of rolling with advantage or disadvantage, including both rolls
+#[derive(Debug, Serialize)]
+struct DieRollWithMode {
+    total: i32,
+    #[serde(flatten)]
+    result: RollResult,
+}
+
+/// Roll a given type of die, optionally with advantage or disadvantage
+#[tracing::instrument]
+async fn roll(
+    path: DieRoll,
+    Query(query): Query<DieRollOptions>,
+    seed: UnversionedSeed,
+) -> Response {
+    let mut rng = seed.rng();
+    let response = match query.mode {
+        Some(mode) => {
+            let result = path.die.roll_with_mode(&mut rng, mode);
+            Json(DieRollWithMode {
+                total: result.total(),
+                result,
+            })
+            .into_response()
+        }
+        None => Json(path.die.roll(&mut rng)).into_response(),
+    };
+    (seed, response).into_response()
+}
+
+/// Either a dice expression, such as `"2d6+3"`, or a number of dice for each
+/// type of die
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MultipleRoll {
+    Expression(String),
+    Dice(HashMap<Die, usize>),
+}
+
+/// Roll multiple dice at once. Can either specify a dice expression, or a
+/// number of dice for each type of die
 #[tracing::instrument]
-async fn roll(path: DieRoll) -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(path.die.roll(&mut rng))
+async fn roll_multiple(seed: UnversionedSeed, Json(payload): Json<MultipleRoll>) -> Response {
+    let mut rng = seed.rng();
+    match payload {
+        MultipleRoll::Expression(expression) => match expression.parse::<Expression>() {
+            Ok(expression) => (seed, Json(expression.roll(&mut rng))).into_response(),
+            Err(error) => (StatusCode::UNPROCESSABLE_ENTITY, error.to_string()).into_response(),
+        },
+        MultipleRoll::Dice(dice) => (
+            seed,
+            Json(
+                // Roll in a consistent order so that seeds are reproducible
+                dice.into_iter()
+                    .sorted_by_key(|(die, _)| die.to_string())
+                    .map(|(die, num)| (die, die.roll_multiple(&mut rng, num).collect::<Vec<_>>()))
+                    .collect::<HashMap<_, _>>(),
+            ),
+        )
+            .into_response(),
+    }
 }
 
-/// Roll multiple dice at once. Can specify a number of dice for each type of die
+/// Roll a pool of dice and count how many of them meet the target number
 #[tracing::instrument]
-async fn roll_multiple(Json(payload): Json<HashMap<Die, usize>>) -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(
-        payload
-            .into_iter()
-            .map(|(die, num)| (die, die.roll_multiple(&mut rng, num).collect::<Vec<_>>()))
-            .collect::<HashMap<_, _>>(),
-    )
+async fn roll_pool(seed: UnversionedSeed, Json(pool): Json<Pool>) -> Response {
+    if pool.amount() > MAX_DICE {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!(
+                "Too many dice requested: {}. A pool can roll at most {MAX_DICE} dice.",
+                pool.amount()
+            ),
+        )
+            .into_response();
+    }
+
+    let mut rng = seed.rng();
+    (seed, Json(pool.roll(&mut rng))).into_response()
+}
+
+/// Percentiles reported for every roll
+const PERCENTILES: [u8; 7] = [5, 10, 25, 50, 75, 90, 95];
+
+#[derive(Debug, Deserialize)]
+struct StatsOptions {
+    /// Dice expression to calculate. A `+` needs to be encoded as `%2B` in
+    /// the query string, otherwise it is decoded as a space and the
+    /// expression is rejected.
+    roll: String,
+}
+
+/// Exact statistics for a dice expression
+#[derive(Debug, Serialize)]
+struct RollStats {
+    roll: String,
+    min: i32,
+    max: i32,
+    mean: f64,
+    variance: f64,
+    std_dev: f64,
+    percentiles: BTreeMap<u8, i32>,
+    probabilities: BTreeMap<i32, f64>,
+}
+
+/// Calculate the exact probability of every result of a dice expression,
+/// without rolling it
+#[tracing::instrument]
+async fn stats(Query(query): Query<StatsOptions>) -> Response {
+    let distribution = query
+        .roll
+        .parse::<Expression>()
+        .map_err(|e| match e {
+            ParseExpressionError::MissingSign(_) => {
+                format!("{e} A `+` in the query string needs to be encoded as `%2B`.")
+            }
+            _ => e.to_string(),
+        })
+        .and_then(|expression| {
+            expression
+                .distribution()
+                .map(|d| (expression, d))
+                .map_err(|e| e.to_string())
+        });
+
+    match distribution {
+        Ok((expression, distribution)) => Json(RollStats {
+            roll: expression.to_string(),
+            min: distribution.min(),
+            max: distribution.max(),
+            mean: distribution.mean(),
+            variance: distribution.variance(),
+            std_dev: distribution.std_dev(),
+            percentiles: PERCENTILES
+                .into_iter()
+                .map(|p| (p, distribution.percentile(p.into())))
+                .collect(),
+            probabilities: distribution.probabilities().collect(),
+        })
+        .into_response(),
+        Err(error) => (StatusCode::UNPROCESSABLE_ENTITY, error).into_response(),
+    }
 }
@@ -15,7 +15,9 @@
 )]
 
 use std::{
+    io,
     net::{SocketAddr, TcpListener},
+    path::PathBuf,
     sync::Arc,
     time::Duration,
 };
@@ -39,6 +41,7 @@ mod deities;
 mod dice;
 mod metrics;
 mod names;
+mod seed;
 mod sizes;
 
 // Setup tracing
@@ -115,6 +118,9 @@ struct CliConfig {
     /// SSL Key value
     #[clap(value_parser, env, long)]
     ssl_key: Option<String>,
+    /// Directory of homebrew pantheons to load, as `.toml` or `.json` files
+    #[clap(value_parser, env, long)]
+    pantheons: Option<PathBuf>,
 }
 
 /// Derived server config from `Config` options
@@ -136,10 +142,16 @@ impl Config {
     /// Parse config from command line
     ///
     /// # Errors
-    /// Errors if can't bind to port or read from cert files
-    pub async fn parse() -> std::io::Result<Self> {
+    /// Errors if can't bind to port, read from cert files, or load homebrew
+    /// pantheons
+    pub async fn parse() -> io::Result<Self> {
         let config = CliConfig::parse();
 
+        if let Some(dir) = config.pantheons {
+            ::deities::homebrew::load_dir(dir)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
         let tls = if let (Some(cert), Some(key)) = (config.ssl_cert, config.ssl_key) {
             Some(RustlsConfig::from_pem(cert.as_bytes().to_vec(), key.as_bytes().to_vec()).await?)
         } else {
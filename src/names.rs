@@ -4,6 +4,8 @@ use names::Name;
 use serde::Deserialize;
 use strum::IntoEnumIterator;
 
+use crate::seed::Seed;
+
 /// Routes related to names
 pub fn routes() -> Router {
     Router::from(Resource::named("names").index(index))
@@ -24,7 +26,7 @@ struct NameGenerator {
 
 /// Create a new name for the given generator type
 #[tracing::instrument]
-async fn create(path: NameGenerator) -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(path.name.gen(&mut rng))
+async fn create(path: NameGenerator, seed: Seed) -> impl IntoResponse {
+    let mut rng = seed.rng();
+    (seed, Json(path.name.gen(&mut rng)))
 }
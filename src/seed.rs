@@ -0,0 +1,125 @@
+//! # Seed
+//!
+//! Seeds for making generated results reproducible
+
+use std::convert::Infallible;
+
+use axum::{
+    async_trait,
+    extract::{rejection::QueryRejection, FromRequestParts, Query},
+    http::{request::Parts, HeaderName, HeaderValue},
+    response::{IntoResponseParts, ResponseParts},
+};
+use rand::Rng;
+use rand_utils::GeneratorVersion;
+use serde::Deserialize;
+
+/// Header the seed used for a response is returned in
+pub const SEED_HEADER: HeaderName = HeaderName::from_static("x-seed");
+
+/// Header the generator version used for a response is returned in
+pub const VERSION_HEADER: HeaderName = HeaderName::from_static("x-generator-version");
+
+/// Seed for the random number generator used by a request. Taken from the
+/// optional `seed` query parameter, or chosen at random if there isn't one.
+/// The optional `version` query parameter chooses which version of the
+/// generators to use, defaulting to the latest.
+///
+/// Returned in the `x-seed` and `x-generator-version` headers when used as
+/// part of a response, so that the same result can be generated again by
+/// passing them back in.
+#[derive(Clone, Copy, Debug)]
+pub struct Seed {
+    seed: u64,
+    version: GeneratorVersion,
+}
+
+impl Seed {
+    /// Random number generator seeded with this seed
+    pub fn rng(self) -> impl Rng {
+        self.version.rng_from_seed(self.seed)
+    }
+
+    /// Version of the generators to use with this seed
+    pub fn version(self) -> GeneratorVersion {
+        self.version
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SeedQuery {
+    seed: Option<u64>,
+    version: Option<GeneratorVersion>,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Seed {
+    type Rejection = QueryRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(SeedQuery { seed, version }) = Query::from_request_parts(parts, state).await?;
+        Ok(Self {
+            seed: seed.unwrap_or_else(|| rand_utils::rng_from_entropy().gen()),
+            version: version.unwrap_or(GeneratorVersion::LATEST),
+        })
+    }
+}
+
+impl IntoResponseParts for Seed {
+    type Error = Infallible;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let mut res = UnversionedSeed { seed: self.seed }.into_response_parts(res)?;
+        res.headers_mut().insert(
+            VERSION_HEADER,
+            HeaderValue::from_str(&self.version.to_string())
+                .expect("versions should be valid header values"),
+        );
+        Ok(res)
+    }
+}
+
+/// Seed for routes whose output doesn't depend on the generator version, such
+/// as dice rolls. Only taken from the optional `seed` query parameter, and only
+/// returned in the `x-seed` header.
+#[derive(Clone, Copy, Debug)]
+pub struct UnversionedSeed {
+    seed: u64,
+}
+
+impl UnversionedSeed {
+    /// Random number generator seeded with this seed. Always uses the RNG of
+    /// the first generator version, so that seeds keep working if a later
+    /// version changes it.
+    pub fn rng(self) -> impl Rng {
+        GeneratorVersion::V1.rng_from_seed(self.seed)
+    }
+}
+
+/// Only the seed is parsed, so an unused `version` parameter is ignored
+#[derive(Debug, Deserialize)]
+struct UnversionedSeedQuery {
+    seed: Option<u64>,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for UnversionedSeed {
+    type Rejection = QueryRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(UnversionedSeedQuery { seed }) = Query::from_request_parts(parts, state).await?;
+        Ok(Self {
+            seed: seed.unwrap_or_else(|| rand_utils::rng_from_entropy().gen()),
+        })
+    }
+}
+
+impl IntoResponseParts for UnversionedSeed {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        res.headers_mut()
+            .insert(SEED_HEADER, HeaderValue::from(self.seed));
+        Ok(res)
+    }
+}
@@ -4,6 +4,8 @@ use serde::Deserialize;
 use sizes::HeightAndWeightTable;
 use strum::IntoEnumIterator;
 
+use crate::seed::Seed;
+
 /// Routes related to height and weight tables
 pub fn routes() -> Router {
     Router::from(Resource::named("height-and-weight").index(index)).merge(Router::from(
@@ -25,7 +27,7 @@ struct Table {
 
 /// Create a new height and weight for the given generator type
 #[tracing::instrument]
-async fn create(path: Table) -> impl IntoResponse {
-    let mut rng = rand_utils::rng_from_entropy();
-    Json(path.table.gen(&mut rng))
+async fn create(path: Table, seed: Seed) -> impl IntoResponse {
+    let mut rng = seed.rng();
+    (seed, Json(path.table.gen(&mut rng)))
 }
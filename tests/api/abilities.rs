@@ -1,7 +1,16 @@
+use abilities::Ability;
 use axum::{body::Body, http::Method};
+use serde_json::{json, Value};
+use strum::IntoEnumIterator;
 
 use crate::TestServer;
 
+/// Score for each ability in a set of serialized ability scores, without any
+/// of the other fields
+pub fn each_ability(ability_scores: &Value) -> impl Iterator<Item = &Value> {
+    Ability::iter().map(|a| &ability_scores[a.to_string()])
+}
+
 #[tokio::test]
 async fn generate_ability_scores() {
     let mut server = TestServer::new();
@@ -13,7 +22,16 @@ async fn generate_ability_scores() {
 
     // Assert all keys are present
     assert_eq!(
-        ["CHA", "CON", "DEX", "INT", "STR", "WIS"],
+        [
+            "CHA",
+            "CON",
+            "DEX",
+            "INT",
+            "STR",
+            "WIS",
+            "combat_role",
+            "proficiency_bonus"
+        ],
         rolls
             .as_object()
             .unwrap()
@@ -23,15 +41,114 @@ async fn generate_ability_scores() {
     );
 
     // Assert all values are valid
-    assert!(rolls
-        .as_object()
-        .unwrap()
-        .values()
-        .all(|v| (3..=18).contains(&v["score"].as_u64().unwrap())));
-
-    assert!(rolls
-        .as_object()
-        .unwrap()
-        .values()
-        .all(|v| (-5..=5).contains(&v["modifier"].as_i64().unwrap())));
+    assert!(each_ability(&rolls).all(|v| (3..=18).contains(&v["score"].as_u64().unwrap())));
+
+    assert!(each_ability(&rolls).all(|v| (-5..=5).contains(&v["modifier"].as_i64().unwrap())));
+}
+
+#[tokio::test]
+async fn generate_point_buy_ability_scores() {
+    let mut server = TestServer::new();
+
+    let scores = server
+        .request(Method::POST, "/abilities?method=point-buy", Body::empty())
+        .await
+        .unwrap();
+
+    let base_scores = each_ability(&scores)
+        .map(|v| u8::try_from(v["base"].as_u64().unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(base_scores.len(), 6);
+    assert_eq!(abilities::validate_point_buy(base_scores), Ok(27));
+}
+
+#[tokio::test]
+async fn generate_standard_array_ability_scores() {
+    let mut server = TestServer::new();
+
+    let scores = server
+        .request(
+            Method::POST,
+            "/abilities?method=standard-array",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    let mut base_scores = each_ability(&scores)
+        .map(|v| v["base"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+    base_scores.sort_unstable();
+    assert_eq!(base_scores, [8, 10, 12, 13, 14, 15]);
+
+    for method in [
+        "roll",
+        "roll-in-order",
+        "roll-reroll-ones",
+        "roll-seven-drop-worst",
+    ] {
+        let scores = server
+            .request(
+                Method::POST,
+                &format!("/abilities?method={method}"),
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        assert!(each_ability(&scores).all(|v| (3..=18).contains(&v["base"].as_u64().unwrap())));
+    }
+}
+
+#[tokio::test]
+async fn generate_ability_scores_for_combat_role() {
+    let mut server = TestServer::new();
+
+    let scores = server
+        .request(
+            Method::POST,
+            "/abilities?method=standard-array&role=front-line",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(scores["combat_role"], "front-line");
+    assert_eq!(scores["STR"]["base"], 15);
+    assert_eq!(scores["CON"]["base"], 14);
+}
+
+#[tokio::test]
+async fn generate_ability_scores_with_proficiencies() {
+    let mut server = TestServer::new();
+
+    let scores = server
+        .request(
+            Method::POST,
+            "/abilities?skill=arcana&skill=athletics&expertise=stealth&saving_throw=WIS",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    let modifier = |ability: &str| scores[ability]["modifier"].as_i64().unwrap();
+    assert_eq!(
+        scores["skills"]["arcana"],
+        json!({ "proficiency": "proficient", "bonus": modifier("INT") + 2 })
+    );
+    assert_eq!(
+        scores["skills"]["stealth"],
+        json!({ "proficiency": "expertise", "bonus": modifier("DEX") + 4 })
+    );
+    assert_eq!(
+        scores["skills"]["history"],
+        json!({ "proficiency": "none", "bonus": modifier("INT") })
+    );
+    assert_eq!(
+        scores["saving_throws"]["WIS"],
+        json!({ "proficiency": "proficient", "bonus": modifier("WIS") + 2 })
+    );
+    assert_eq!(
+        scores["saving_throws"]["STR"],
+        json!({ "proficiency": "none", "bonus": modifier("STR") })
+    );
 }
@@ -1,4 +1,7 @@
-use axum::{body::Body, http::Method};
+use axum::{
+    body::Body,
+    http::{Method, StatusCode},
+};
 
 use crate::TestServer;
 
@@ -13,3 +16,129 @@ async fn generate_alignment() {
 
     assert!(!alignment.as_str().unwrap().is_empty());
 }
+
+#[tokio::test]
+async fn generate_influenced_alignment() {
+    let mut server = TestServer::new();
+
+    // Enough influences that anything else is vanishingly unlikely
+    let alignment = server
+        .request(
+            Method::POST,
+            &format!("/alignments?{}", ["alignment=LG"; 10].join("&")),
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(alignment, "Lawful Good");
+
+    let alignment = server
+        .request(
+            Method::POST,
+            &format!(
+                "/alignments?{}&{}",
+                ["attitude=c"; 10].join("&"),
+                ["morality=Evil"; 10].join("&")
+            ),
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(alignment, "Chaotic Evil");
+
+    let (status, message) = server
+        .request_error(Method::POST, "/alignments?alignment=XY", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(message.contains("isn't a valid alignment"));
+}
+
+#[tokio::test]
+async fn alignment_probabilities() {
+    let mut server = TestServer::new();
+
+    let probabilities = server
+        .request(Method::GET, "/alignments/probabilities", Body::empty())
+        .await
+        .unwrap();
+    let probabilities = probabilities.as_object().unwrap();
+    assert_eq!(probabilities.len(), 9);
+    // Without influences, every alignment is equally likely
+    for probability in probabilities.values() {
+        assert!((probability.as_f64().unwrap() - 1.0 / 9.0).abs() < 1e-9);
+    }
+
+    let probabilities = server
+        .request(
+            Method::GET,
+            "/alignments/probabilities?attitude=Lawful&morality=Good&morality=Neutral",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    let probabilities = probabilities.as_object().unwrap();
+    let total = probabilities
+        .values()
+        .map(|p| p.as_f64().unwrap())
+        .sum::<f64>();
+    assert!((total - 1.0).abs() < 1e-9);
+    let most_likely = probabilities
+        .iter()
+        .max_by(|(_, a), (_, b)| a.as_f64().unwrap().total_cmp(&b.as_f64().unwrap()))
+        .unwrap()
+        .0;
+    assert_eq!(most_likely, "Lawful Good");
+    assert!(probabilities["Lawful Neutral"].as_f64() > probabilities["Chaotic Evil"].as_f64());
+}
+
+#[tokio::test]
+async fn generate_allowed_alignments() {
+    let mut server = TestServer::new();
+
+    for _ in 0..20 {
+        let alignment = server
+            .request(
+                Method::POST,
+                "/alignments?allowed=any%20non-good&morality=Good",
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        assert!(!alignment.as_str().unwrap().ends_with("Good"));
+    }
+
+    let alignment = server
+        .request(Method::POST, "/alignments?allowed=unaligned", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(alignment, "Unaligned");
+
+    let probabilities = server
+        .request(
+            Method::GET,
+            "/alignments/probabilities?allowed=any%20chaotic",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        probabilities
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        ["Chaotic Evil", "Chaotic Good", "Chaotic Neutral"]
+    );
+
+    let (status, message) = server
+        .request_error(
+            Method::POST,
+            "/alignments?allowed=any%20neutral",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(message.contains("isn't a valid alignment option"));
+}
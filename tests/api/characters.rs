@@ -1,6 +1,11 @@
-use axum::{body::Body, http::Method};
+use axum::{
+    body::Body,
+    http::{Method, StatusCode},
+};
 
-use crate::TestServer;
+use serde_json::json;
+
+use crate::{abilities::each_ability, TestServer};
 
 #[tokio::test]
 async fn generate_characters() {
@@ -16,7 +21,16 @@ async fn generate_characters() {
 
     // Ability scores are present
     assert_eq!(
-        ["CHA", "CON", "DEX", "INT", "STR", "WIS"],
+        [
+            "CHA",
+            "CON",
+            "DEX",
+            "INT",
+            "STR",
+            "WIS",
+            "combat_role",
+            "proficiency_bonus"
+        ],
         character["ability_scores"]
             .as_object()
             .unwrap()
@@ -35,3 +49,176 @@ async fn generate_characters() {
     assert!(character["weight"].as_i64().unwrap() > 0);
     assert!(!character["size"].as_str().unwrap().is_empty());
 }
+
+#[tokio::test]
+async fn generate_point_buy_characters() {
+    let mut server = TestServer::new();
+
+    let character = server
+        .request(
+            Method::POST,
+            "/characters?ability_method=point-buy",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    let base_scores = each_ability(&character["ability_scores"])
+        .map(|v| u8::try_from(v["base"].as_u64().unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(abilities::validate_point_buy(base_scores), Ok(27));
+}
+
+#[tokio::test]
+async fn generate_characters_with_ability_scores() {
+    let mut server = TestServer::new();
+
+    let character = server
+        .request(
+            Method::POST,
+            "/characters",
+            Body::from(
+                serde_json::to_vec(&json!({
+                    "ability_scores": {
+                        "STR": 8, "DEX": 10, "CON": 12, "INT": 13, "WIS": 14, "CHA": 15
+                    }
+                }))
+                .unwrap(),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let scores = &character["ability_scores"];
+    for (ability, base) in [
+        ("STR", 8),
+        ("DEX", 10),
+        ("CON", 12),
+        ("INT", 13),
+        ("WIS", 14),
+        ("CHA", 15),
+    ] {
+        assert_eq!(scores[ability]["base"], base);
+        assert!(scores[ability]["score"].as_u64().unwrap() >= base);
+    }
+}
+
+#[tokio::test]
+async fn reject_illegal_point_buy_ability_scores() {
+    let mut server = TestServer::new();
+
+    let (status, message) = server
+        .request_error(
+            Method::POST,
+            "/characters?ability_method=point-buy",
+            Body::from(
+                serde_json::to_vec(&json!({
+                    "ability_scores": {
+                        "STR": 15, "DEX": 15, "CON": 15, "INT": 15, "WIS": 8, "CHA": 8
+                    }
+                }))
+                .unwrap(),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(message.contains("36 points were spent"));
+}
+
+#[tokio::test]
+async fn reject_ability_scores_with_combat_role() {
+    let mut server = TestServer::new();
+
+    let (status, message) = server
+        .request_error(
+            Method::POST,
+            "/characters?combat_role=front-line",
+            Body::from(
+                serde_json::to_vec(&json!({
+                    "ability_scores": {
+                        "STR": 8, "DEX": 10, "CON": 12, "INT": 13, "WIS": 14, "CHA": 15
+                    }
+                }))
+                .unwrap(),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(message.contains("combat_role"));
+}
+
+#[tokio::test]
+async fn generate_higher_level_characters() {
+    let mut server = TestServer::new();
+
+    let character = server
+        .request(Method::POST, "/characters?level=8", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(character["level"], 8);
+    assert_eq!(character["ability_scores"]["proficiency_bonus"], 3);
+    let improvements = each_ability(&character["ability_scores"])
+        .map(|v| v["improvement"].as_u64().unwrap())
+        .sum::<u64>();
+    assert!(improvements <= 4);
+
+    let (status, message) = server
+        .request_error(Method::POST, "/characters?level=21", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(message.contains("level 21"));
+}
+
+#[tokio::test]
+async fn generate_characters_with_fixed_racial_increases() {
+    let mut server = TestServer::new();
+
+    let character = server
+        .request(
+            Method::POST,
+            "/characters?racial_increases=fixed",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    // Only dragonborn are available, with +2 STR and +1 CHA
+    let scores = &character["ability_scores"];
+    assert!(character["race"].as_str().unwrap().contains("Dragonborn"));
+    assert_eq!(scores["STR"]["racial_increase"], 2);
+    assert_eq!(scores["CHA"]["racial_increase"], 1);
+}
+
+#[tokio::test]
+async fn generate_characters_with_proficiencies() {
+    let mut server = TestServer::new();
+
+    let character = server
+        .request(
+            Method::POST,
+            "/characters?level=5&skill=perception&expertise=persuasion&saving_throw=CON",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+
+    let scores = &character["ability_scores"];
+    let modifier = |ability: &str| scores[ability]["modifier"].as_i64().unwrap();
+    assert_eq!(
+        scores["skills"]["perception"],
+        json!({ "proficiency": "proficient", "bonus": modifier("WIS") + 3 })
+    );
+    assert_eq!(
+        scores["skills"]["persuasion"],
+        json!({ "proficiency": "expertise", "bonus": modifier("CHA") + 6 })
+    );
+    assert_eq!(
+        scores["saving_throws"]["CON"],
+        json!({ "proficiency": "proficient", "bonus": modifier("CON") + 3 })
+    );
+}
@@ -1,5 +1,8 @@
-use axum::{body::Body, http::Method};
-use deities::Domain;
+use axum::{
+    body::Body,
+    http::{Method, StatusCode},
+};
+use deities::{Deity, Domain, Pantheon, Patron};
 use itertools::Itertools;
 use serde_json::json;
 use strum::IntoEnumIterator;
@@ -58,3 +61,249 @@ async fn generate_deity_with_domain_filter() {
             .contains(&json!("Life")));
     }
 }
+
+#[tokio::test]
+async fn reject_domains_missing_from_version() {
+    let mut server = TestServer::new();
+
+    for domain in ["Order", "Peace", "Twilight"] {
+        let (status, message) = server
+            .request_error(
+                Method::POST,
+                &format!("/deities?version=v1&domain={domain}"),
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(message.contains(domain), "{message}");
+
+        let deity = server
+            .request(
+                Method::POST,
+                &format!("/deities?version=v2&domain={domain}"),
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        assert!(deity["domains"]
+            .as_array()
+            .unwrap()
+            .contains(&json!(domain)));
+    }
+}
+
+#[tokio::test]
+async fn generate_influenced_deity() {
+    let mut server = TestServer::new();
+
+    let deity = server
+        .request(
+            Method::POST,
+            "/deities?attitude=Lawful&morality=G&alignment=Neutral%20Good",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert!(!deity["name"].as_str().unwrap().is_empty());
+
+    let (status, _) = server
+        .request_error(Method::POST, "/deities?morality=Lawful", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_pantheon_options() {
+    let mut server = TestServer::new();
+
+    let options = server
+        .request(Method::GET, "/deities/pantheons", Body::empty())
+        .await
+        .unwrap();
+
+    assert_eq!(options, json!(Pantheon::all().collect::<Vec<_>>()));
+}
+
+#[tokio::test]
+async fn get_pantheon_deities() {
+    let mut server = TestServer::new();
+
+    let deities = server
+        .request(
+            Method::GET,
+            "/deities/pantheons/Forgotten%20Realms",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        deities,
+        json!(Pantheon::ForgottenRealms.deities(None).into_owned())
+    );
+
+    let (status, _) = server
+        .request_error(Method::GET, "/deities/pantheons/Unknown", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_deity_by_name() {
+    let mut server = TestServer::new();
+
+    let deities = server
+        .request(Method::GET, "/deities/zeus", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(deities.as_array().unwrap().len(), 1);
+    assert_eq!(deities[0]["name"], json!("Zeus"));
+    assert_eq!(deities[0]["pantheon"], json!("Greek"));
+
+    // Worshipped in both the Celtic and Forgotten Realms pantheons
+    let deities = server
+        .request(Method::GET, "/deities/Oghma", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(deities.as_array().unwrap().len(), 2);
+
+    let (status, _) = server
+        .request_error(Method::GET, "/deities/Nobody", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn filter_deities() {
+    let mut server = TestServer::new();
+
+    let deities = server
+        .request(Method::GET, "/deities", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(deities.as_array().unwrap().len(), Deity::iter().count());
+
+    let deities = server
+        .request(
+            Method::GET,
+            "/deities?domain=Life&alignment=any%20good&pantheon=Greek&pantheon=Norse",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    let deities = deities.as_array().unwrap();
+    assert!(!deities.is_empty());
+    for deity in deities {
+        assert!(deity["domains"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("Life")));
+        assert!(deity["alignment"].as_str().unwrap().ends_with("Good"));
+        assert!([json!("Greek"), json!("Norse")].contains(&deity["pantheon"]));
+    }
+}
+
+#[tokio::test]
+async fn generate_deity_with_pantheon_influences() {
+    let mut server = TestServer::new();
+
+    let mut greek_or_norse = 0;
+    for _ in 0..20 {
+        let deity = server
+            .request(
+                Method::POST,
+                "/deities?pantheon=Greek&pantheon=Norse&morality=Good",
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        if [json!("Greek"), json!("Norse")].contains(&deity["pantheon"]) {
+            greek_or_norse += 1;
+        }
+    }
+    assert!(greek_or_norse > 10);
+
+    let (status, _) = server
+        .request_error(Method::POST, "/deities?pantheon=Unknown", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn generate_optional_deity() {
+    let mut server = TestServer::new();
+
+    let mut none = false;
+    for _ in 0..1000 {
+        let deity = server
+            .request(
+                Method::POST,
+                "/deities?required=false&morality=Evil&morality=Evil",
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        if deity.is_null() {
+            none = true;
+            break;
+        }
+    }
+    assert!(none);
+}
+
+#[tokio::test]
+async fn list_patrons() {
+    let mut server = TestServer::new();
+
+    let patrons = server
+        .request(Method::GET, "/deities/patrons", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(patrons, json!(Patron::iter().collect::<Vec<_>>()));
+
+    let fiends = server
+        .request(
+            Method::GET,
+            "/deities/patrons?kind=Fiend&alignment=any%20lawful",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    let fiends = fiends.as_array().unwrap();
+    assert!(!fiends.is_empty());
+    for fiend in fiends {
+        assert_eq!(fiend["kind"], json!("Fiend"));
+        assert!(fiend["alignment"].as_str().unwrap().starts_with("Lawful"));
+    }
+}
+
+#[tokio::test]
+async fn generate_patron() {
+    let mut server = TestServer::new();
+
+    let mut great_old_ones = 0;
+    for _ in 0..20 {
+        let patron = server
+            .request(
+                Method::POST,
+                "/deities/patrons?kind=Great%20Old%20One&morality=Evil",
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+        if patron["kind"] == json!("Great Old One") {
+            great_old_ones += 1;
+        }
+    }
+    assert!(great_old_ones > 10);
+
+    let (status, _) = server
+        .request_error(Method::POST, "/deities/patrons?kind=Deity", Body::empty())
+        .await
+        .unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use axum::{body::Body, http::Method};
+use axum::{
+    body::Body,
+    http::{Method, StatusCode},
+};
 use dice::Die;
 use serde_json::json;
 use statrs::{
@@ -35,11 +38,60 @@ async fn die_roll() {
     }
 }
 
+#[tokio::test]
+async fn custom_die_roll() {
+    let mut server = TestServer::new();
+
+    for (die, range) in [
+        ("d2", 1..=2),
+        ("d3", 1..=3),
+        ("d30", 1..=30),
+        ("dF", -1..=1),
+    ] {
+        let roll = server
+            .request(Method::POST, &format!("/dice/{die}/roll"), Body::empty())
+            .await
+            .unwrap();
+
+        assert!(range.contains(&roll.as_i64().unwrap()));
+    }
+}
+
+#[tokio::test]
+async fn die_roll_with_mode() {
+    let mut server = TestServer::new();
+
+    for mode in ["advantage", "disadvantage"] {
+        let resp = server
+            .request(
+                Method::POST,
+                &format!("/dice/d20/roll?mode={mode}"),
+                Body::empty(),
+            )
+            .await
+            .unwrap();
+
+        let kept = resp["kept"][0].as_u64().unwrap();
+        let discarded = resp["discarded"][0].as_u64().unwrap();
+        assert_eq!(resp["total"], kept);
+        assert!((1..=20).contains(&kept));
+        assert!((1..=20).contains(&discarded));
+        if mode == "advantage" {
+            assert!(kept >= discarded);
+        } else {
+            assert!(kept <= discarded);
+        }
+    }
+}
+
 #[tokio::test]
 async fn roll_multiple_die_rolls() {
     let mut server = TestServer::new();
 
-    let items = Die::iter().enumerate().map(|(i, d)| (d, i));
+    let items = Die::iter()
+        .chain([Die::with_sides(3).unwrap(), Die::with_sides(30).unwrap()])
+        .enumerate()
+        .map(|(i, d)| (d, i));
     let body: HashMap<Die, usize> = items.clone().collect();
 
     let resp = server
@@ -58,7 +110,106 @@ async fn roll_multiple_die_rolls() {
 
         // And they are within the bounds
         for roll in rolls {
-            assert!((1..=u64::from(die)).contains(&(roll.as_u64().unwrap())));
+            assert!(
+                (i64::from(die.min())..=i64::from(die.max())).contains(&(roll.as_i64().unwrap()))
+            );
         }
     }
 }
+
+#[tokio::test]
+async fn roll_expression() {
+    let mut server = TestServer::new();
+
+    let resp = server
+        .request(
+            Method::POST,
+            "/dice/roll",
+            Body::from(serde_json::to_vec(&json!("2d6+1d4+3")).unwrap()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(resp["expression"], "2d6+1d4+3");
+    assert!((6..=19).contains(&resp["total"].as_i64().unwrap()));
+
+    let terms = resp["terms"].as_array().unwrap();
+    assert_eq!(terms.len(), 3);
+    assert_eq!(terms[0]["kept"].as_array().unwrap().len(), 2);
+    assert_eq!(terms[1]["kept"].as_array().unwrap().len(), 1);
+    assert_eq!(terms[2]["total"], 3);
+}
+
+#[tokio::test]
+async fn roll_stats() {
+    let mut server = TestServer::new();
+
+    let resp = server
+        .request(Method::GET, "/dice/stats?roll=4d6kh3%2B1", Body::empty())
+        .await
+        .unwrap();
+
+    assert_eq!(resp["roll"], "4d6kh3+1");
+    assert_eq!(resp["min"], 4);
+    assert_eq!(resp["max"], 19);
+    assert!((resp["mean"].as_f64().unwrap() - 13.2446).abs() < 0.0001);
+    assert_eq!(resp["percentiles"]["50"], 13);
+
+    let probabilities = resp["probabilities"].as_object().unwrap();
+    assert_eq!(probabilities.len(), 16);
+    assert!((probabilities["4"].as_f64().unwrap() - 1.0 / 1296.0).abs() < 1e-9);
+    let total = probabilities
+        .values()
+        .map(|p| p.as_f64().unwrap())
+        .sum::<f64>();
+    assert!((total - 1.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn roll_stats_with_unencoded_plus() {
+    let mut server = TestServer::new();
+
+    let (status, message) = server
+        .request_error(Method::GET, "/dice/stats?roll=2d6+3", Body::empty())
+        .await
+        .unwrap();
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(message.contains("%2B"));
+}
+
+#[tokio::test]
+async fn roll_pool() {
+    let mut server = TestServer::new();
+
+    let resp = server
+        .request(
+            Method::POST,
+            "/dice/pool",
+            Body::from(
+                serde_json::to_vec(&json!({
+                    "amount": 8,
+                    "target": 6,
+                    "subtract_ones": true,
+                    "botch": true
+                }))
+                .unwrap(),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let rolls = resp["rolls"].as_array().unwrap();
+    assert_eq!(rolls.len(), 8);
+    assert!(rolls
+        .iter()
+        .all(|r| (1..=10).contains(&r.as_i64().unwrap())));
+
+    let successes = rolls.iter().filter(|r| r.as_i64().unwrap() >= 6).count();
+    let ones = rolls.iter().filter(|r| r.as_i64().unwrap() == 1).count();
+    assert_eq!(resp["successes"], successes);
+    assert_eq!(resp["failures"], 8 - successes);
+    assert_eq!(resp["ones"], ones);
+    assert_eq!(resp["net_successes"], successes.saturating_sub(ones));
+    assert!(["success", "failure", "botch"].contains(&resp["outcome"].as_str().unwrap()));
+}
@@ -1,3 +1,4 @@
+//! Integration tests for the API routes
 #![warn(
     clippy::pedantic,
     future_incompatible,
@@ -17,7 +18,7 @@ use std::{
 
 use axum::{
     body::Body,
-    http::{header, Method, Request, StatusCode},
+    http::{header, HeaderMap, Method, Request, StatusCode},
     routing::RouterIntoService,
 };
 use bag_of_holding::{app, start_server, Config};
@@ -32,6 +33,7 @@ mod characters;
 mod deities;
 mod dice;
 mod names;
+mod seed;
 mod sizes;
 
 /// Use the entire server for tests
@@ -60,6 +62,16 @@ impl TestServer {
         endpoint: &str,
         body: Body,
     ) -> Result<Value, Box<dyn Error>> {
+        Ok(self.request_with_headers(method, endpoint, body).await?.1)
+    }
+
+    /// Same as `request`, but also returns the response headers
+    async fn request_with_headers(
+        &mut self,
+        method: Method,
+        endpoint: &str,
+        body: Body,
+    ) -> Result<(HeaderMap, Value), Box<dyn Error>> {
         let request = Request::builder()
             .method(method)
             .uri(format!("http://{}{endpoint}", self.addr))
@@ -73,7 +85,34 @@ impl TestServer {
             .unwrap();
         assert_eq!(response.status(), StatusCode::OK);
 
+        let headers = response.headers().clone();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        Ok((headers, serde_json::from_slice(&body[..])?))
+    }
+
+    /// Make a request that is expected to be rejected, returning the status
+    /// code and error message
+    async fn request_error(
+        &mut self,
+        method: Method,
+        endpoint: &str,
+        body: Body,
+    ) -> Result<(StatusCode, String), Box<dyn Error>> {
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("http://{}{endpoint}", self.addr))
+            .header(header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .body(body)?;
+        let response = ServiceExt::<Request<Body>>::ready(&mut self.app)
+            .await
+            .unwrap()
+            .call(request)
+            .await
+            .unwrap();
+        assert!(response.status().is_client_error());
+
+        let status = response.status();
         let body = response.into_body().collect().await.unwrap().to_bytes();
-        Ok(serde_json::from_slice(&body[..])?)
+        Ok((status, String::from_utf8(body.to_vec())?))
     }
 }
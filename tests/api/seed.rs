@@ -0,0 +1,100 @@
+use axum::{body::Body, http::Method};
+use serde_json::json;
+
+use crate::TestServer;
+
+/// Every route that generates something random, along with a request body
+const ROUTES: [(&str, Option<&str>); 11] = [
+    ("/abilities", None),
+    ("/alignments", None),
+    ("/characters", None),
+    ("/deities", None),
+    ("/deities/domains", None),
+    ("/height-and-weight/dragonborn", None),
+    ("/names/human", None),
+    ("/dice/d20/roll", None),
+    ("/dice/roll", Some("4d6kh3+2d8!")),
+    ("/dice/pool", None),
+    ("/dice/roll", None),
+];
+
+fn body(endpoint: &str, expression: Option<&str>) -> Body {
+    let body = match (endpoint, expression) {
+        (_, Some(expression)) => json!(expression),
+        ("/dice/pool", _) => json!({ "amount": 10, "target": 6 }),
+        ("/dice/roll", _) => json!({ "d4": 3, "d6": 2, "d8": 1, "d20": 5 }),
+        _ => return Body::empty(),
+    };
+    Body::from(serde_json::to_vec(&body).unwrap())
+}
+
+#[tokio::test]
+async fn same_seed_same_result() {
+    let mut server = TestServer::new();
+
+    for (endpoint, expression) in ROUTES {
+        let seeded = format!("{endpoint}?seed=1234");
+
+        let (headers, first) = server
+            .request_with_headers(Method::POST, &seeded, body(endpoint, expression))
+            .await
+            .unwrap();
+        assert_eq!(headers["x-seed"], "1234");
+        if endpoint.starts_with("/dice") {
+            // Dice rolls don't depend on the generator version
+            assert!(!headers.contains_key("x-generator-version"), "{endpoint}");
+        } else {
+            assert_eq!(headers["x-generator-version"], "v4", "{endpoint}");
+        }
+
+        for _ in 0..5 {
+            let again = server
+                .request(Method::POST, &seeded, body(endpoint, expression))
+                .await
+                .unwrap();
+            assert_eq!(first, again, "{endpoint}");
+        }
+    }
+}
+
+#[tokio::test]
+async fn returned_seed_reproduces_result() {
+    let mut server = TestServer::new();
+
+    for (endpoint, expression) in ROUTES {
+        let (headers, first) = server
+            .request_with_headers(Method::POST, endpoint, body(endpoint, expression))
+            .await
+            .unwrap();
+        let seed = headers["x-seed"].to_str().unwrap();
+        let query = match headers.get("x-generator-version") {
+            Some(version) => format!("seed={seed}&version={}", version.to_str().unwrap()),
+            None => format!("seed={seed}"),
+        };
+
+        let again = server
+            .request(
+                Method::POST,
+                &format!("{endpoint}?{query}"),
+                body(endpoint, expression),
+            )
+            .await
+            .unwrap();
+        assert_eq!(first, again, "{endpoint}");
+    }
+}
+
+#[tokio::test]
+async fn dice_routes_ignore_version() {
+    let mut server = TestServer::new();
+
+    let (headers, _) = server
+        .request_with_headers(
+            Method::POST,
+            "/dice/d20/roll?seed=1&version=bogus",
+            Body::empty(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(headers["x-seed"], "1");
+}